@@ -0,0 +1,20 @@
+using System.Runtime.InteropServices;
+using BepuPhysics.Collidables;
+using BepuUtilities;
+
+namespace Bepuvy.Callbacks;
+
+/// <summary>
+/// Broad phase overlap enumerator that forwards each overlapping collidable to a native function pointer.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public unsafe struct BroadPhaseOverlapHandler : IBreakableForEach<CollidableReference>
+{
+    public void* Context;
+    public delegate* unmanaged<void*, CollidableReference, byte> OnOverlapFunction;
+
+    public bool LoopBody(CollidableReference collidable)
+    {
+        return OnOverlapFunction(Context, collidable) != 0;
+    }
+}
@@ -10,24 +10,27 @@ namespace AbominationInterop;
 [StructLayout(LayoutKind.Sequential)]
 public unsafe struct NarrowPhaseCallbacksInterop
 {
-    public delegate* unmanaged<InstanceHandle, void> InitializeFunction;
-    public delegate* unmanaged<InstanceHandle, void> DisposeFunction;
-    public delegate* unmanaged<InstanceHandle, int, CollidableReference, CollidableReference, float*, byte> AllowContactGenerationFunction;
-    public delegate* unmanaged<InstanceHandle, int, CollidablePair, int, int, byte> AllowContactGenerationBetweenChildrenFunction;
-    public delegate* unmanaged<InstanceHandle, int, CollidablePair, ConvexContactManifold*, PairMaterialProperties*, byte> ConfigureConvexContactManifoldFunction;
-    public delegate* unmanaged<InstanceHandle, int, CollidablePair, NonconvexContactManifold*, PairMaterialProperties*, byte> ConfigureNonconvexContactManifoldFunction;
-    public delegate* unmanaged<InstanceHandle, int, CollidablePair, int, int, ConvexContactManifold*, byte> ConfigureChildContactManifoldFunction;
+    public delegate* unmanaged<InstanceHandle, void*, void> InitializeFunction;
+    public delegate* unmanaged<InstanceHandle, void*, void> DisposeFunction;
+    public delegate* unmanaged<InstanceHandle, int, CollidableReference, CollidableReference, float*, void*, byte> AllowContactGenerationFunction;
+    public delegate* unmanaged<InstanceHandle, int, CollidablePair, int, int, void*, byte> AllowContactGenerationBetweenChildrenFunction;
+    public delegate* unmanaged<InstanceHandle, int, CollidablePair, ConvexContactManifold*, PairMaterialProperties*, void*, byte> ConfigureConvexContactManifoldFunction;
+    public delegate* unmanaged<InstanceHandle, int, CollidablePair, NonconvexContactManifold*, PairMaterialProperties*, void*, byte> ConfigureNonconvexContactManifoldFunction;
+    public delegate* unmanaged<InstanceHandle, int, CollidablePair, int, int, ConvexContactManifold*, void*, byte> ConfigureChildContactManifoldFunction;
+    public void* Context;
 }
 
 public unsafe struct NarrowPhaseCallbacks : INarrowPhaseCallbacks
 {
-    public delegate* unmanaged<InstanceHandle, void> InitializeFunction;
-    public delegate* unmanaged<InstanceHandle, void> DisposeFunction;
-    public delegate* unmanaged<InstanceHandle, int, CollidableReference, CollidableReference, float*, byte> AllowContactGenerationFunction;
-    public delegate* unmanaged<InstanceHandle, int, CollidablePair, int, int, byte> AllowContactGenerationBetweenChildrenFunction;
-    public delegate* unmanaged<InstanceHandle, int, CollidablePair, ConvexContactManifold*, PairMaterialProperties*, byte> ConfigureConvexContactManifoldFunction;
-    public delegate* unmanaged<InstanceHandle, int, CollidablePair, NonconvexContactManifold*, PairMaterialProperties*, byte> ConfigureNonconvexContactManifoldFunction;
-    public delegate* unmanaged<InstanceHandle, int, CollidablePair, int, int, ConvexContactManifold*, byte> ConfigureChildContactManifoldFunction;
+    public delegate* unmanaged<InstanceHandle, void*, void> InitializeFunction;
+    public delegate* unmanaged<InstanceHandle, void*, void> DisposeFunction;
+    public delegate* unmanaged<InstanceHandle, int, CollidableReference, CollidableReference, float*, void*, byte> AllowContactGenerationFunction;
+    public delegate* unmanaged<InstanceHandle, int, CollidablePair, int, int, void*, byte> AllowContactGenerationBetweenChildrenFunction;
+    public delegate* unmanaged<InstanceHandle, int, CollidablePair, ConvexContactManifold*, PairMaterialProperties*, void*, byte> ConfigureConvexContactManifoldFunction;
+    public delegate* unmanaged<InstanceHandle, int, CollidablePair, NonconvexContactManifold*, PairMaterialProperties*, void*, byte> ConfigureNonconvexContactManifoldFunction;
+    public delegate* unmanaged<InstanceHandle, int, CollidablePair, int, int, ConvexContactManifold*, void*, byte> ConfigureChildContactManifoldFunction;
+    //Opaque native state handed back to every callback so the native side doesn't need to look it up by simulation handle.
+    public void* Context;
 
     public InstanceHandle Simulation;
 
@@ -39,23 +42,23 @@ public unsafe struct NarrowPhaseCallbacks : INarrowPhaseCallbacks
     {
         Simulation = simulation;
         if (InitializeFunction != null)
-            InitializeFunction(simulation);
+            InitializeFunction(simulation, Context);
     }
     public void Dispose()
     {
         if (DisposeFunction != null)
-            DisposeFunction(Simulation);
+            DisposeFunction(Simulation, Context);
     }
 
     //Note that a number of these convert refs into pointers. These are safe; all such references originate on the stack or pinned memory.
     public bool AllowContactGeneration(int workerIndex, CollidableReference a, CollidableReference b, ref float speculativeMargin)
     {
-        return AllowContactGenerationFunction(Simulation, workerIndex, a, b, (float*)Unsafe.AsPointer(ref speculativeMargin)) != 0;
+        return AllowContactGenerationFunction(Simulation, workerIndex, a, b, (float*)Unsafe.AsPointer(ref speculativeMargin), Context) != 0;
     }
 
     public bool AllowContactGeneration(int workerIndex, CollidablePair pair, int childIndexA, int childIndexB)
     {
-        return AllowContactGenerationBetweenChildrenFunction(Simulation, workerIndex, pair, childIndexA, childIndexB) != 0;
+        return AllowContactGenerationBetweenChildrenFunction(Simulation, workerIndex, pair, childIndexA, childIndexB, Context) != 0;
     }
 
     public bool ConfigureContactManifold<TManifold>(int workerIndex, CollidablePair pair, ref TManifold manifold, out PairMaterialProperties pairMaterial) where TManifold : unmanaged, IContactManifold<TManifold>
@@ -67,16 +70,16 @@ public unsafe struct NarrowPhaseCallbacks : INarrowPhaseCallbacks
         var pairMaterialPointer = (PairMaterialProperties*)Unsafe.AsPointer(ref pairMaterial);
         if (typeof(TManifold) == typeof(ConvexContactManifold))
         {
-            return ConfigureConvexContactManifoldFunction(Simulation, workerIndex, pair, (ConvexContactManifold*)Unsafe.AsPointer(ref manifold), pairMaterialPointer) != 0;
+            return ConfigureConvexContactManifoldFunction(Simulation, workerIndex, pair, (ConvexContactManifold*)Unsafe.AsPointer(ref manifold), pairMaterialPointer, Context) != 0;
         }
         else
         {
-            return ConfigureNonconvexContactManifoldFunction(Simulation, workerIndex, pair, (NonconvexContactManifold*)Unsafe.AsPointer(ref manifold), pairMaterialPointer) != 0;
+            return ConfigureNonconvexContactManifoldFunction(Simulation, workerIndex, pair, (NonconvexContactManifold*)Unsafe.AsPointer(ref manifold), pairMaterialPointer, Context) != 0;
         }
     }
 
     public bool ConfigureContactManifold(int workerIndex, CollidablePair pair, int childIndexA, int childIndexB, ref ConvexContactManifold manifold)
     {
-        return ConfigureChildContactManifoldFunction(Simulation, workerIndex, pair, childIndexA, childIndexB, (ConvexContactManifold*)Unsafe.AsPointer(ref manifold)) != 0;
+        return ConfigureChildContactManifoldFunction(Simulation, workerIndex, pair, childIndexA, childIndexB, (ConvexContactManifold*)Unsafe.AsPointer(ref manifold), Context) != 0;
     }
 }
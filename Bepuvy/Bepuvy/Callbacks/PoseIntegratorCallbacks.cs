@@ -23,9 +23,9 @@ public unsafe struct PoseIntegratorCallbacksInterop
     public byte UseScalarCallback;
 
     [FieldOffset(8)]
-    public delegate* unmanaged<InstanceHandle, void> Initialize;
+    public delegate* unmanaged<InstanceHandle, void*, void> Initialize;
     [FieldOffset(16)]
-    public delegate* unmanaged<InstanceHandle, float, void> PrepareForIntegration;
+    public delegate* unmanaged<InstanceHandle, float, void*, void> PrepareForIntegration;
     
     [FieldOffset(24)]
     public delegate* unmanaged<InstanceHandle, Vector<int>, Vector3Wide, QuaternionWide, BodyInertiaWide, Vector<int>, int, Vector<float>, BodyVelocityWide*, void*, void> IntegrateVelocity;
@@ -59,8 +59,8 @@ public unsafe struct PoseIntegratorCallbacks<TAngularConservationMode, TUnconstr
 
     public bool IntegrateVelocityForKinematics => typeof(TKinematicIntegration) == typeof(True);
 
-    public delegate* unmanaged<InstanceHandle, void> InitializeFunction;
-    public delegate* unmanaged<InstanceHandle, float, void> PrepareForIntegrationFunction;
+    public delegate* unmanaged<InstanceHandle, void*, void> InitializeFunction;
+    public delegate* unmanaged<InstanceHandle, float, void*, void> PrepareForIntegrationFunction;
     public void* IntegrateVelocityFunction;
     
     
@@ -75,7 +75,7 @@ public unsafe struct PoseIntegratorCallbacks<TAngularConservationMode, TUnconstr
     {
         Simulation = simulation;
         if (InitializeFunction != null)
-            InitializeFunction(simulation);
+            InitializeFunction(simulation, Callback.Callback);
     }
 
     public void IntegrateVelocity(Vector<int> bodyIndices, Vector3Wide position, QuaternionWide orientation, BodyInertiaWide localInertia, Vector<int> integrationMask, int workerIndex, Vector<float> dt, ref BodyVelocityWide velocity)
@@ -89,17 +89,15 @@ public unsafe struct PoseIntegratorCallbacks<TAngularConservationMode, TUnconstr
     {
         //Really SHOULD be a prepare function provided, but it's not technically required like the velocity integration one is.
         if (PrepareForIntegrationFunction != null)
-            PrepareForIntegrationFunction(Simulation, dt);
+            PrepareForIntegrationFunction(Simulation, dt, Callback.Callback);
     }
 }
 
 
-public class RustCallback: IDisposable
+/// <summary>
+/// Opaque pointer to the native state the pose integrator callbacks of a simulation are passed.
+/// </summary>
+public class RustCallback
 {
     public unsafe void* Callback;
-
-    public void Dispose()
-    {
-        // TODO release managed resources here
-    }
 }
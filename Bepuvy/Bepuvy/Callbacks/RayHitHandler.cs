@@ -0,0 +1,48 @@
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using BepuPhysics.Collidables;
+using BepuPhysics.Trees;
+
+namespace Bepuvy.Callbacks;
+
+/// <summary>
+/// Information about a ray's intersection with a collidable, as reported to the native side.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public struct RayHit
+{
+    public float T;
+    public Vector3 Normal;
+    public CollidableReference Collidable;
+    public int ChildIndex;
+}
+
+/// <summary>
+/// Ray hit handler that forwards filtering and hits to native function pointers.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public unsafe struct RayHitHandler : IRayHitHandler
+{
+    public void* Context;
+    public delegate* unmanaged<void*, CollidableReference, byte> AllowTestFunction;
+    public delegate* unmanaged<void*, RayHit*, float*, void> OnRayHitFunction;
+
+    public bool AllowTest(CollidableReference collidable)
+    {
+        return AllowTestFunction == null || AllowTestFunction(Context, collidable) != 0;
+    }
+
+    public bool AllowTest(CollidableReference collidable, int childIndex)
+    {
+        //Filtering is only exposed per collidable; children of an allowed collidable are always tested.
+        return true;
+    }
+
+    public void OnRayHit(in RayData ray, ref float maximumT, float t, Vector3 normal, CollidableReference collidable, int childIndex)
+    {
+        var hit = new RayHit { T = t, Normal = normal, Collidable = collidable, ChildIndex = childIndex };
+        //maximumT is a reference into the traversal's stack state, so handing out a pointer for the duration of the call is safe.
+        OnRayHitFunction(Context, &hit, (float*)Unsafe.AsPointer(ref maximumT));
+    }
+}
@@ -24,5 +24,24 @@ public unsafe struct SolveDescriptionInterop
     /// Callback executed to determine how many velocity iterations should be used for a given substep. If null, or if it returns a non-positive value, the <see cref="VelocityIterationCount"/> will be used instead.
     /// </summary>
     [FieldOffset(16)]
-    public delegate* unmanaged<int, int> VelocityIterationScheduler;
+    public delegate* unmanaged<void*, int, int> VelocityIterationScheduler;
+    /// <summary>
+    /// Context passed to every call of <see cref="VelocityIterationScheduler"/>.
+    /// </summary>
+    [FieldOffset(24)]
+    public void* VelocityIterationSchedulerContext;
+}
+
+/// <summary>
+/// Adapts a native velocity iteration scheduler and its context to the managed <see cref="BepuPhysics.SubstepVelocityIterationScheduler"/> delegate.
+/// </summary>
+public unsafe class NativeVelocityIterationScheduler
+{
+    public delegate* unmanaged<void*, int, int> Function;
+    public void* Context;
+
+    public int Schedule(int substepIndex)
+    {
+        return Function(Context, substepIndex);
+    }
 }
\ No newline at end of file
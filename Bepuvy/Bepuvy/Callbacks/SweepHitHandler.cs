@@ -0,0 +1,54 @@
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using BepuPhysics;
+using BepuPhysics.Collidables;
+
+namespace Bepuvy.Callbacks;
+
+/// <summary>
+/// Information about a swept shape's impact with a collidable, as reported to the native side.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public struct SweepHit
+{
+    public float T;
+    public Vector3 Location;
+    public Vector3 Normal;
+    public CollidableReference Collidable;
+    public byte StartsOverlapping;
+}
+
+/// <summary>
+/// Sweep hit handler that forwards filtering and hits to native function pointers.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public unsafe struct SweepHitHandler : ISweepHitHandler
+{
+    public void* Context;
+    public delegate* unmanaged<void*, CollidableReference, byte> AllowTestFunction;
+    public delegate* unmanaged<void*, SweepHit*, float*, void> OnHitFunction;
+
+    public bool AllowTest(CollidableReference collidable)
+    {
+        return AllowTestFunction == null || AllowTestFunction(Context, collidable) != 0;
+    }
+
+    public bool AllowTest(CollidableReference collidable, int child)
+    {
+        //Filtering is only exposed per collidable; children of an allowed collidable are always tested.
+        return true;
+    }
+
+    public void OnHit(ref float maximumT, float t, Vector3 hitLocation, Vector3 hitNormal, CollidableReference collidable)
+    {
+        var hit = new SweepHit { T = t, Location = hitLocation, Normal = hitNormal, Collidable = collidable };
+        OnHitFunction(Context, &hit, (float*)Unsafe.AsPointer(ref maximumT));
+    }
+
+    public void OnHitAtZeroT(ref float maximumT, CollidableReference collidable)
+    {
+        var hit = new SweepHit { Collidable = collidable, StartsOverlapping = 1 };
+        OnHitFunction(Context, &hit, (float*)Unsafe.AsPointer(ref maximumT));
+    }
+}
@@ -15,32 +15,61 @@ public static partial class Entrypoints
     /// Buffers will be suballocated from blocks.</param>
     /// <param name="expectedUsedSlotCountPerPool">Number of suballocations to preallocate reference space for.
     /// This does not preallocate actual blocks, just the space to hold references that are waiting in the pool.</param>
+    /// <returns>Handle of the created pool, or a null handle if creation failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "BufferPool.Create")]
     [return: TypeName("BufferPoolHandle")]
     public static InstanceHandle CreateBufferPool(int minimumBlockAllocationSize = 131072, int expectedUsedSlotCountPerPool = 16)
     {
-        return bufferPools.Add(new BufferPool(minimumBlockAllocationSize, expectedUsedSlotCountPerPool));
+        try
+        {
+            return bufferPools.Add(new BufferPool(minimumBlockAllocationSize, expectedUsedSlotCountPerPool));
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return default;
+        }
     }
 
     /// <summary>
     /// Releases all allocations held by the buffer pool. The buffer pool remains in a usable state.
     /// </summary>
     /// <param name="handle">Buffer pool to clear.</param>
+    /// <returns>1 if the pool was cleared, 0 if clearing failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "BufferPool.Clear")]
-    public static void ClearBufferPool([TypeName("BufferPoolHandle")] InstanceHandle handle)
+    public static byte ClearBufferPool([TypeName("BufferPoolHandle")] InstanceHandle handle)
     {
-        bufferPools[handle].Clear();
+        try
+        {
+            bufferPools[handle].Clear();
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
     /// Releases all allocations held by the buffer pool and releases the buffer pool reference. The handle is invalidated.
     /// </summary>
     /// <param name="handle">Buffer pool to destroy.</param>
+    /// <returns>1 if the pool was destroyed, 0 if destroying failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "BufferPool.Destroy")]
-    public static void DestroyBufferPool([TypeName("BufferPoolHandle")] InstanceHandle handle)
+    public static byte DestroyBufferPool([TypeName("BufferPoolHandle")] InstanceHandle handle)
     {
-        bufferPools[handle].Clear();
-        bufferPools.Remove(handle);
+        try
+        {
+            bufferPools[handle].Clear();
+            bufferPools.Remove(handle);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
@@ -48,13 +77,21 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="bufferPoolHandle">Buffer pool to allocate from.</param>
     /// <param name="sizeInBytes">Size of the buffer to allocate in bytes.</param>
-    /// <returns>Allocated buffer.</returns>
+    /// <returns>Allocated buffer, or a buffer with null memory if allocating failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "BufferPool.Allocate")]
     [return: TypeName("ByteBuffer")]
     public static Buffer<byte> Allocate([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, int sizeInBytes)
     {
-        bufferPools[bufferPoolHandle].Take<byte>(sizeInBytes, out var buffer);
-        return buffer;
+        try
+        {
+            bufferPools[bufferPoolHandle].Take<byte>(sizeInBytes, out var buffer);
+            return buffer;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return default;
+        }
     }
 
     /// <summary>
@@ -62,13 +99,21 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="bufferPoolHandle">Buffer pool to allocate from.</param>
     /// <param name="sizeInBytes">Size of the buffer to allocate in bytes.</param>
-    /// <returns>Allocated buffer.</returns>
+    /// <returns>Allocated buffer, or a buffer with null memory if allocating failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "BufferPool.AllocateAtLeast")]
     [return: TypeName("ByteBuffer")]
     public static Buffer<byte> AllocateAtLeast([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, int sizeInBytes)
     {
-        bufferPools[bufferPoolHandle].TakeAtLeast<byte>(sizeInBytes, out var buffer);
-        return buffer;
+        try
+        {
+            bufferPools[bufferPoolHandle].TakeAtLeast<byte>(sizeInBytes, out var buffer);
+            return buffer;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return default;
+        }
     }
 
     /// <summary>
@@ -78,10 +123,20 @@ public static partial class Entrypoints
     /// <param name="buffer">Buffer to resize.</param>
     /// <param name="newSizeInBytes">Target size of the buffer to allocate in bytes.</param>
     /// <param name="copyCount">Number of bytes to copy from the old buffer into the new buffer.</param>
+    /// <returns>1 if the buffer was resized, 0 if resizing failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "BufferPool.Resize")]
-    public static unsafe void Resize([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, [TypeName("ByteBuffer*")] Buffer<byte>* buffer, int newSizeInBytes, int copyCount)
+    public static unsafe byte Resize([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, [TypeName("ByteBuffer*")] Buffer<byte>* buffer, int newSizeInBytes, int copyCount)
     {
-        bufferPools[bufferPoolHandle].Resize(ref *buffer, newSizeInBytes, copyCount);
+        try
+        {
+            bufferPools[bufferPoolHandle].Resize(ref *buffer, newSizeInBytes, copyCount);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
@@ -91,10 +146,20 @@ public static partial class Entrypoints
     /// <param name="buffer">Buffer to resize.</param>
     /// <param name="targetSizeInBytes">Target size of the buffer to allocate in bytes.</param>
     /// <param name="copyCount">Number of bytes to copy from the old buffer into the new buffer.</param>
+    /// <returns>1 if the buffer was resized, 0 if resizing failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "BufferPool.ResizeToAtLeast")]
-    public static unsafe void ResizeToAtLeast([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, [TypeName("ByteBuffer*")] Buffer<byte>* buffer, int targetSizeInBytes, int copyCount)
+    public static unsafe byte ResizeToAtLeast([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, [TypeName("ByteBuffer*")] Buffer<byte>* buffer, int targetSizeInBytes, int copyCount)
     {
-        bufferPools[bufferPoolHandle].ResizeToAtLeast(ref *buffer, targetSizeInBytes, copyCount);
+        try
+        {
+            bufferPools[bufferPoolHandle].ResizeToAtLeast(ref *buffer, targetSizeInBytes, copyCount);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
@@ -102,10 +167,20 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="bufferPoolHandle">Buffer pool to return the buffer to.</param>
     /// <param name="buffer">Buffer to return to the pool.</param>
+    /// <returns>1 if the buffer was returned, 0 if returning failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "BufferPool.Deallocate")]
-    public unsafe static void Deallocate([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, [TypeName("ByteBuffer*")] Buffer<byte>* buffer)
+    public unsafe static byte Deallocate([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, [TypeName("ByteBuffer*")] Buffer<byte>* buffer)
     {
-        bufferPools[bufferPoolHandle].Return(ref *buffer);
+        try
+        {
+            bufferPools[bufferPoolHandle].Return(ref *buffer);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
@@ -113,10 +188,20 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="bufferPoolHandle">Buffer pool to return the buffer to.</param>
     /// <param name="bufferId">Id of the buffer to return to the pool.</param>
+    /// <returns>1 if the buffer was returned, 0 if returning failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) }, EntryPoint = "BufferPool.DeallocateId")]
-    public unsafe static void DeallocateById([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, int bufferId)
+    public unsafe static byte DeallocateById([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, int bufferId)
     {
-        bufferPools[bufferPoolHandle].ReturnUnsafely(bufferId);
+        try
+        {
+            bufferPools[bufferPoolHandle].ReturnUnsafely(bufferId);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
@@ -124,10 +209,20 @@ public static partial class Entrypoints
     /// Includes allocated memory regardless of whether it currently has outstanding references.
     /// </summary>
     /// <param name="bufferPoolHandle">Buffer pool to check the allocation size of.</param>
-    /// <returns>Total number of bytes allocated from native memory in this buffer pool.</returns>
+    /// <param name="size">Total number of bytes allocated from native memory in this buffer pool.</param>
+    /// <returns>1 if the size was computed, 0 if the lookup failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) }, EntryPoint = "BufferPool.GetAllocatedMemorySizeInPool")]
-    public unsafe static ulong GetAllocatedMemorySizeInPool([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle)
+    public unsafe static byte GetAllocatedMemorySizeInPool([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, ulong* size)
     {
-        return bufferPools[bufferPoolHandle].GetTotalAllocatedByteCount();
+        try
+        {
+            *size = bufferPools[bufferPoolHandle].GetTotalAllocatedByteCount();
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 }
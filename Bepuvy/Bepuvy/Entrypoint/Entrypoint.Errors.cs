@@ -0,0 +1,69 @@
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using System.Text;
+
+namespace Bepuvy;
+
+public static partial class Entrypoints
+{
+    /// <summary>
+    /// Message of the last exception caught by an entrypoint on this thread, if it hasn't been taken yet.
+    /// </summary>
+    [ThreadStatic]
+    private static string? lastError;
+
+    /// <summary>
+    /// Whether the last error recorded on this thread came from a failed handle check.
+    /// </summary>
+    [ThreadStatic]
+    private static bool lastErrorIsInvalidHandle;
+
+    /// <summary>
+    /// Records an exception caught by an entrypoint so the native side can retrieve it after the call reports failure.
+    /// </summary>
+    /// <remarks>Exceptions can't cross an UnmanagedCallersOnly boundary; letting one escape terminates the process.</remarks>
+    private static void SetLastError(Exception exception)
+    {
+        lastError = $"{exception.GetType().FullName}: {exception.Message}";
+        lastErrorIsInvalidHandle = exception is InvalidHandleException;
+    }
+
+    /// <summary>
+    /// Gets the length in bytes of the UTF-8 encoded message of the last error recorded on this thread.
+    /// </summary>
+    /// <returns>Length of the message in bytes, or 0 if there is no error.</returns>
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Errors.GetLastErrorLength")]
+    public static int GetLastErrorLength()
+    {
+        return lastError == null ? 0 : Encoding.UTF8.GetByteCount(lastError);
+    }
+
+    /// <summary>
+    /// Gets whether the last error recorded on this thread came from a failed handle check.
+    /// </summary>
+    /// <returns>1 if the error is an invalid handle, 0 if it is any other error or there is no error.</returns>
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Errors.IsLastErrorInvalidHandle")]
+    public static byte IsLastErrorInvalidHandle()
+    {
+        return lastError != null && lastErrorIsInvalidHandle ? (byte)1 : (byte)0;
+    }
+
+    /// <summary>
+    /// Copies the UTF-8 encoded message of the last error recorded on this thread and clears it.
+    /// </summary>
+    /// <param name="buffer">Buffer to copy the message into.</param>
+    /// <param name="bufferLength">Length of the buffer in bytes. Longer messages are truncated.</param>
+    /// <returns>Number of bytes written to the buffer.</returns>
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Errors.TakeLastError")]
+    public static unsafe int TakeLastError(byte* buffer, int bufferLength)
+    {
+        if (lastError == null)
+            return 0;
+        var bytes = Encoding.UTF8.GetBytes(lastError);
+        lastError = null;
+        lastErrorIsInvalidHandle = false;
+        var count = Math.Min(bytes.Length, bufferLength);
+        bytes.AsSpan(0, count).CopyTo(new Span<byte>(buffer, bufferLength));
+        return count;
+    }
+}
@@ -0,0 +1,134 @@
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using BepuPhysics;
+using BepuPhysics.Collidables;
+using Bepuvy.Callbacks;
+
+namespace Bepuvy;
+
+public static partial class Entrypoints
+{
+    /// <summary>
+    /// Casts a ray against every collidable in the simulation.
+    /// </summary>
+    /// <param name="simulationHandle">Handle of the simulation to cast the ray against.</param>
+    /// <param name="origin">Origin of the ray.</param>
+    /// <param name="direction">Direction of the ray. Hit distances are measured in units of this vector's length.</param>
+    /// <param name="maximumT">Maximum distance along the ray to test.</param>
+    /// <param name="handler">Callbacks to filter collidables and receive hits.</param>
+    /// <returns>1 if the query ran, 0 if it failed and an error was recorded.</returns>
+    /// <remarks>Hits are reported in traversal order, not sorted by distance.</remarks>
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.RayCast")]
+    public static unsafe byte RayCast([TypeName("SimulationHandle")] InstanceHandle simulationHandle, Vector3 origin, Vector3 direction, float maximumT, [TypeName("RayHitHandler*")] RayHitHandler* handler)
+    {
+        try
+        {
+            simulations[simulationHandle].RayCast(origin, direction, maximumT, ref *handler);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
+    }
+
+    //Sweeps need the concrete shape type to pick sweep tasks and convergence thresholds, so dispatch on the type id here.
+    private static unsafe void SweepConvex(Simulation simulation, void* shapeData, int shapeType, in RigidPose pose, in BodyVelocity velocity, float maximumT, SweepHitHandler* handler)
+    {
+        if (shapeType == Sphere.TypeId)
+            simulation.Sweep(*(Sphere*)shapeData, pose, velocity, maximumT, simulation.BufferPool, ref *handler);
+        else if (shapeType == Capsule.TypeId)
+            simulation.Sweep(*(Capsule*)shapeData, pose, velocity, maximumT, simulation.BufferPool, ref *handler);
+        else if (shapeType == Box.TypeId)
+            simulation.Sweep(*(Box*)shapeData, pose, velocity, maximumT, simulation.BufferPool, ref *handler);
+        else if (shapeType == Triangle.TypeId)
+            simulation.Sweep(*(Triangle*)shapeData, pose, velocity, maximumT, simulation.BufferPool, ref *handler);
+        else if (shapeType == Cylinder.TypeId)
+            simulation.Sweep(*(Cylinder*)shapeData, pose, velocity, maximumT, simulation.BufferPool, ref *handler);
+        else if (shapeType == ConvexHull.TypeId)
+            simulation.Sweep(*(ConvexHull*)shapeData, pose, velocity, maximumT, simulation.BufferPool, ref *handler);
+        else
+            throw new ArgumentException($"Shape type {shapeType} is not a convex shape and can't be swept.");
+    }
+
+    /// <summary>
+    /// Sweeps a convex shape against every collidable in the simulation.
+    /// </summary>
+    /// <param name="simulationHandle">Handle of the simulation to sweep the shape against.</param>
+    /// <param name="shapeData">Pointer to the shape to sweep.</param>
+    /// <param name="shapeType">Type id of the shape pointed to by shapeData. Must be a convex shape type.</param>
+    /// <param name="pose">Starting pose of the shape.</param>
+    /// <param name="velocity">Velocity of the shape over the sweep.</param>
+    /// <param name="maximumT">Maximum time of impact to test.</param>
+    /// <param name="handler">Callbacks to filter collidables and receive hits.</param>
+    /// <returns>1 if the query ran, 0 if it failed and an error was recorded.</returns>
+    /// <remarks>Hits are reported in traversal order, not sorted by time of impact.</remarks>
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Sweep")]
+    public static unsafe byte Sweep([TypeName("SimulationHandle")] InstanceHandle simulationHandle, void* shapeData, int shapeType, RigidPose pose, BodyVelocity velocity, float maximumT, [TypeName("SweepHitHandler*")] SweepHitHandler* handler)
+    {
+        try
+        {
+            SweepConvex(simulations[simulationHandle], shapeData, shapeType, pose, velocity, maximumT, handler);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
+    }
+
+    /// <summary>
+    /// Sweeps a convex shape stored in the simulation against every collidable in the simulation.
+    /// </summary>
+    /// <param name="simulationHandle">Handle of the simulation to sweep the shape against.</param>
+    /// <param name="shape">Index of the shape to sweep. Must refer to a convex shape.</param>
+    /// <param name="pose">Starting pose of the shape.</param>
+    /// <param name="velocity">Velocity of the shape over the sweep.</param>
+    /// <param name="maximumT">Maximum time of impact to test.</param>
+    /// <param name="handler">Callbacks to filter collidables and receive hits.</param>
+    /// <returns>1 if the query ran, 0 if it failed and an error was recorded.</returns>
+    /// <remarks>Hits are reported in traversal order, not sorted by time of impact.</remarks>
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.SweepIndexed")]
+    public static unsafe byte SweepIndexed([TypeName("SimulationHandle")] InstanceHandle simulationHandle, TypedIndex shape, RigidPose pose, BodyVelocity velocity, float maximumT, [TypeName("SweepHitHandler*")] SweepHitHandler* handler)
+    {
+        try
+        {
+            CheckShape(simulationHandle, shape);
+            var simulation = simulations[simulationHandle];
+            simulation.Shapes[shape.Type].GetShapeData(shape.Index, out var shapeData, out _);
+            SweepConvex(simulation, shapeData, shape.Type, pose, velocity, maximumT, handler);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
+    }
+
+    /// <summary>
+    /// Finds every collidable whose broad-phase bounds overlap a box, covering both the active and static trees.
+    /// </summary>
+    /// <param name="simulationHandle">Handle of the simulation to query.</param>
+    /// <param name="min">Minimum corner of the query box.</param>
+    /// <param name="max">Maximum corner of the query box.</param>
+    /// <param name="handler">Callbacks to receive overlapping collidables.</param>
+    /// <returns>1 if the query ran, 0 if it failed and an error was recorded.</returns>
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.QueryAabb")]
+    public static unsafe byte QueryAabb([TypeName("SimulationHandle")] InstanceHandle simulationHandle, Vector3 min, Vector3 max, [TypeName("BroadPhaseOverlapHandler*")] BroadPhaseOverlapHandler* handler)
+    {
+        try
+        {
+            simulations[simulationHandle].BroadPhase.GetOverlaps(min, max, ref *handler);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
+    }
+}
@@ -15,10 +15,19 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to add the shape to.</param>
     /// <param name="sphere">Shape to add to the simulation.</param>
+    /// <returns>Index of the added shape, or an index to no shape if adding failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.AddSphere")]
     public static TypedIndex AddSphere([TypeName("SimulationHandle")] InstanceHandle simulationHandle, Sphere sphere)
     {
-        return simulations[simulationHandle].Shapes.Add(sphere);
+        try
+        {
+            return simulations[simulationHandle].Shapes.Add(sphere);
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return default;
+        }
     }
 
     /// <summary>
@@ -26,10 +35,19 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to add the shape to.</param>
     /// <param name="capsule">Shape to add to the simulation.</param>
+    /// <returns>Index of the added shape, or an index to no shape if adding failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.AddCapsule")]
     public static TypedIndex AddCapsule([TypeName("SimulationHandle")] InstanceHandle simulationHandle, Capsule capsule)
     {
-        return simulations[simulationHandle].Shapes.Add(capsule);
+        try
+        {
+            return simulations[simulationHandle].Shapes.Add(capsule);
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return default;
+        }
     }
 
     /// <summary>
@@ -37,10 +55,19 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to add the shape to.</param>
     /// <param name="box">Shape to add to the simulation.</param>
+    /// <returns>Index of the added shape, or an index to no shape if adding failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.AddBox")]
     public static TypedIndex AddBox([TypeName("SimulationHandle")] InstanceHandle simulationHandle, Box box)
     {
-        return simulations[simulationHandle].Shapes.Add(box);
+        try
+        {
+            return simulations[simulationHandle].Shapes.Add(box);
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return default;
+        }
     }
 
     /// <summary>
@@ -48,10 +75,19 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to add the shape to.</param>
     /// <param name="triangle">Shape to add to the simulation.</param>
+    /// <returns>Index of the added shape, or an index to no shape if adding failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.AddTriangle")]
     public static TypedIndex AddTriangle([TypeName("SimulationHandle")] InstanceHandle simulationHandle, Triangle triangle)
     {
-        return simulations[simulationHandle].Shapes.Add(triangle);
+        try
+        {
+            return simulations[simulationHandle].Shapes.Add(triangle);
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return default;
+        }
     }
 
     /// <summary>
@@ -59,10 +95,19 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to add the shape to.</param>
     /// <param name="cylinder">Shape to add to the simulation.</param>
+    /// <returns>Index of the added shape, or an index to no shape if adding failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.AddCylinder")]
     public static TypedIndex AddCylinder([TypeName("SimulationHandle")] InstanceHandle simulationHandle, Cylinder cylinder)
     {
-        return simulations[simulationHandle].Shapes.Add(cylinder);
+        try
+        {
+            return simulations[simulationHandle].Shapes.Add(cylinder);
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return default;
+        }
     }
 
     /// <summary>
@@ -70,10 +115,19 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to add the shape to.</param>
     /// <param name="convexHull">Shape to add to the simulation.</param>
+    /// <returns>Index of the added shape, or an index to no shape if adding failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.AddConvexHull")]
     public static TypedIndex AddConvexHull([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConvexHull convexHull)
     {
-        return simulations[simulationHandle].Shapes.Add(convexHull);
+        try
+        {
+            return simulations[simulationHandle].Shapes.Add(convexHull);
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return default;
+        }
     }
 
     /// <summary>
@@ -81,10 +135,19 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to add the shape to.</param>
     /// <param name="bigCompound">Shape to add to the simulation.</param>
+    /// <returns>Index of the added shape, or an index to no shape if adding failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.AddCompound")]
     public static TypedIndex AddCompound([TypeName("SimulationHandle")] InstanceHandle simulationHandle, Compound bigCompound)
     {
-        return simulations[simulationHandle].Shapes.Add(bigCompound);
+        try
+        {
+            return simulations[simulationHandle].Shapes.Add(bigCompound);
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return default;
+        }
     }
 
     /// <summary>
@@ -92,10 +155,19 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to add the shape to.</param>
     /// <param name="bigCompound">Shape to add to the simulation.</param>
+    /// <returns>Index of the added shape, or an index to no shape if adding failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.AddBigCompound")]
     public static TypedIndex AddBigCompound([TypeName("SimulationHandle")] InstanceHandle simulationHandle, BigCompound bigCompound)
     {
-        return simulations[simulationHandle].Shapes.Add(bigCompound);
+        try
+        {
+            return simulations[simulationHandle].Shapes.Add(bigCompound);
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return default;
+        }
     }
 
     /// <summary>
@@ -103,10 +175,19 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to add the shape to.</param>
     /// <param name="mesh">Shape to add to the simulation.</param>
+    /// <returns>Index of the added shape, or an index to no shape if adding failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.AddMesh")]
     public static TypedIndex AddMesh([TypeName("SimulationHandle")] InstanceHandle simulationHandle, Mesh mesh)
     {
-        return simulations[simulationHandle].Shapes.Add(mesh);
+        try
+        {
+            return simulations[simulationHandle].Shapes.Add(mesh);
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return default;
+        }
     }
 
     /// <summary>
@@ -114,10 +195,21 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to remove the shape from.</param>
     /// <param name="shape">Shape to remove from the simulation.</param>
+    /// <returns>1 if the shape was removed, 0 if removing failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.RemoveShape")]
-    public static void RemoveShape([TypeName("SimulationHandle")] InstanceHandle simulationHandle, TypedIndex shape)
+    public static byte RemoveShape([TypeName("SimulationHandle")] InstanceHandle simulationHandle, TypedIndex shape)
     {
-        simulations[simulationHandle].Shapes.Remove(shape);
+        try
+        {
+            CheckShape(simulationHandle, shape);
+            simulations[simulationHandle].Shapes.Remove(shape);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
@@ -126,11 +218,22 @@ public static partial class Entrypoints
     /// <param name="simulationHandle">Handle of the simulation to remove the shape from.</param>
     /// <param name="bufferPoolHandle">Buffer pool to return shape resources to, if any.</param>
     /// <param name="shape">Shape to remove from the simulation.</param>
+    /// <returns>1 if the shape was removed, 0 if removing failed and an error was recorded.</returns>
     /// <remarks>The same buffer pool must be used for both allocation and deallocation.</remarks>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.RemoveAndDestroyShape")]
-    public static void RemoveAndDestroyShape([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, TypedIndex shape)
+    public static byte RemoveAndDestroyShape([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, TypedIndex shape)
     {
-        simulations[simulationHandle].Shapes.RemoveAndDispose(shape, bufferPools[bufferPoolHandle]);
+        try
+        {
+            CheckShape(simulationHandle, shape);
+            simulations[simulationHandle].Shapes.RemoveAndDispose(shape, bufferPools[bufferPoolHandle]);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
@@ -139,11 +242,22 @@ public static partial class Entrypoints
     /// <param name="simulationHandle">Handle of the simulation to remove the shape from.</param>
     /// <param name="bufferPoolHandle">Buffer pool to return shape resources to, if any.</param>
     /// <param name="shape">Shape to remove from the simulation.</param>
+    /// <returns>1 if the shape was removed, 0 if removing failed and an error was recorded.</returns>
     /// <remarks>The same buffer pool must be used for both allocation and deallocation.</remarks>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.RemoveAndDestroyShapeRecursively")]
-    public static void RemoveAndDestroyShapeRecursively([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, TypedIndex shape)
+    public static byte RemoveAndDestroyShapeRecursively([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, TypedIndex shape)
     {
-        simulations[simulationHandle].Shapes.RecursivelyRemoveAndDispose(shape, bufferPools[bufferPoolHandle]);
+        try
+        {
+            CheckShape(simulationHandle, shape);
+            simulations[simulationHandle].Shapes.RecursivelyRemoveAndDispose(shape, bufferPools[bufferPoolHandle]);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
@@ -152,11 +266,21 @@ public static partial class Entrypoints
     /// <param name="bufferPoolHandle">Buffer pool to allocate resources from for the compound's acceleration structures.</param>
     /// <param name="points">Points in the convex hull.</param>
     /// <param name="centerOfMass">Center of mass computed for the hull and subtracted from all the points in the points used for the final shape.</param>
+    /// <param name="hull">Receives the created hull.</param>
+    /// <returns>1 if the hull was created, 0 if creation failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.CreateConvexHull")]
-    public unsafe static ConvexHull CreateConvexHull([TypeName("SimulationHandle")] InstanceHandle bufferPoolHandle, [TypeName("Buffer<Vector3>")] Buffer<Vector3> points, Vector3* centerOfMass)
+    public unsafe static byte CreateConvexHull([TypeName("SimulationHandle")] InstanceHandle bufferPoolHandle, [TypeName("Buffer<Vector3>")] Buffer<Vector3> points, Vector3* centerOfMass, ConvexHull* hull)
     {
-        ConvexHullHelper.CreateShape(points, bufferPools[bufferPoolHandle], out *centerOfMass, out var hull);
-        return hull;
+        try
+        {
+            ConvexHullHelper.CreateShape(points, bufferPools[bufferPoolHandle], out *centerOfMass, out *hull);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
@@ -164,10 +288,20 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="bufferPoolHandle">Buffer pool to return resources to. Must be the same pool that resources were allocated from.</param>
     /// <param name="convexHull">Convex hull to destroy.</param>
+    /// <returns>1 if the buffers were returned, 0 if returning them failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.DestroyConvexHull")]
-    public unsafe static void DestroyConvexHull([TypeName("SimulationHandle")] InstanceHandle bufferPoolHandle, ConvexHull* convexHull)
+    public unsafe static byte DestroyConvexHull([TypeName("SimulationHandle")] InstanceHandle bufferPoolHandle, ConvexHull* convexHull)
     {
-        convexHull->Dispose(bufferPools[bufferPoolHandle]);
+        try
+        {
+            convexHull->Dispose(bufferPools[bufferPoolHandle]);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
@@ -175,10 +309,20 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="bufferPoolHandle">Buffer pool to return resources to. Must be the same pool that resources were allocated from.</param>
     /// <param name="compound">Compound to destroy.</param>
+    /// <returns>1 if the buffers were returned, 0 if returning them failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.DestroyCompound")]
-    public unsafe static void DestroyCompound([TypeName("SimulationHandle")] InstanceHandle bufferPoolHandle, Compound* compound)
+    public unsafe static byte DestroyCompound([TypeName("SimulationHandle")] InstanceHandle bufferPoolHandle, Compound* compound)
     {
-        compound->Dispose(bufferPools[bufferPoolHandle]);
+        try
+        {
+            compound->Dispose(bufferPools[bufferPoolHandle]);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
@@ -187,10 +331,21 @@ public static partial class Entrypoints
     /// <param name="simulationHandle">Handle of the simulation to which the shapes referenced by the compound children belong.</param>
     /// <param name="bufferPoolHandle">Buffer pool to allocate resources from for the compound's acceleration structures.</param>
     /// <param name="children">Children of the compound.</param>
+    /// <param name="bigCompound">Receives the created compound.</param>
+    /// <returns>1 if the compound was created, 0 if creation failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.CreateBigCompound")]
-    public static BigCompound CreateBigCompound([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BufferPool")] InstanceHandle bufferPoolHandle, [TypeName("Buffer<CompoundChild>")] Buffer<CompoundChild> children)
+    public static unsafe byte CreateBigCompound([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BufferPool")] InstanceHandle bufferPoolHandle, [TypeName("Buffer<CompoundChild>")] Buffer<CompoundChild> children, BigCompound* bigCompound)
     {
-        return new BigCompound(children, simulations[simulationHandle].Shapes, bufferPools[bufferPoolHandle]);
+        try
+        {
+            *bigCompound = new BigCompound(children, simulations[simulationHandle].Shapes, bufferPools[bufferPoolHandle]);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
@@ -198,10 +353,20 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="bufferPoolHandle">Buffer pool to return resources to. Must be the same pool that resources were allocated from.</param>
     /// <param name="bigCompound">Big compound to destroy.</param>
+    /// <returns>1 if the buffers were returned, 0 if returning them failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.DestroyBigCompound")]
-    public static unsafe void DestroyBigCompound([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, BigCompound* bigCompound)
+    public static unsafe byte DestroyBigCompound([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, BigCompound* bigCompound)
     {
-        bigCompound->Dispose(bufferPools[bufferPoolHandle]);
+        try
+        {
+            bigCompound->Dispose(bufferPools[bufferPoolHandle]);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
@@ -210,11 +375,22 @@ public static partial class Entrypoints
     /// <param name="bufferPoolHandle">Buffer pool to allocate resources from for the compound's acceleration structures.</param>
     /// <param name="triangles">Triangles composing the mesh.</param>
     /// <param name="scale">Scale of the mesh.</param>
+    /// <param name="mesh">Receives the created mesh.</param>
+    /// <returns>1 if the mesh was created, 0 if creation failed and an error was recorded.</returns>
     /// <remarks>This uses a pretty old sweep builder. Large meshes will take a while. There are ways to do this much faster if required; see https://github.com/bepu/bepuphysics2/blob/master/Demos/DemoMeshHelper.cs#L186.</remarks>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Mesh.Create")]
-    public static Mesh CreateMesh([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, [TypeName("Buffer<Triangle>")] Buffer<Triangle> triangles, Vector3 scale)
+    public static unsafe byte CreateMesh([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, [TypeName("Buffer<Triangle>")] Buffer<Triangle> triangles, Vector3 scale, Mesh* mesh)
     {
-        return new Mesh(triangles, scale, bufferPools[bufferPoolHandle]);
+        try
+        {
+            *mesh = new Mesh(triangles, scale, bufferPools[bufferPoolHandle]);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
@@ -222,10 +398,20 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="bufferPoolHandle">Buffer pool to return resources to. Must be the same pool that resources were allocated from.</param>
     /// <param name="mesh">Mesh to destroy.</param>
+    /// <returns>1 if the buffers were returned, 0 if returning them failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Mesh.Destroy")]
-    public unsafe static void DestroyMesh([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, Mesh* mesh)
+    public unsafe static byte DestroyMesh([TypeName("BufferPoolHandle")] InstanceHandle bufferPoolHandle, Mesh* mesh)
     {
-        mesh->Dispose(bufferPools[bufferPoolHandle]);
+        try
+        {
+            mesh->Dispose(bufferPools[bufferPoolHandle]);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
@@ -305,15 +491,29 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="convex">Index of a convex to calculate the inertia for.</param>
     /// <param name="mass">Mass to use in the inertia calculation.</param>
-    /// <returns>Inertia of the shape. If the shape index was not a convex, this returns a zeroed inverse inertia tensor.</returns>
+    /// <param name="inertia">Inertia of the shape. If the shape index was not a convex, this receives a zeroed inverse inertia tensor.</param>
+    /// <returns>1 if the inertia was computed, 0 if computing failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) }, EntryPoint = "Simulation.Shapes.ComputeConvexInertia")]
-    public static BodyInertia ComputeConvexInertia([TypeName("SimulationHandle")] InstanceHandle simulationHandle, TypedIndex convex, float mass)
+    public static unsafe byte ComputeConvexInertia([TypeName("SimulationHandle")] InstanceHandle simulationHandle, TypedIndex convex, float mass, BodyInertia* inertia)
     {
-        if (simulations[simulationHandle].Shapes[convex.Type] is IConvexShapeBatch convexBatch)
+        try
         {
-            return convexBatch.ComputeInertia(convex.Index, mass);
+            CheckShape(simulationHandle, convex);
+            if (simulations[simulationHandle].Shapes[convex.Type] is IConvexShapeBatch convexBatch)
+            {
+                *inertia = convexBatch.ComputeInertia(convex.Index, mass);
+            }
+            else
+            {
+                *inertia = new BodyInertia() { InverseMass = 1f / mass };
+            }
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
         }
-        return new BodyInertia() { InverseMass = 1f / mass };
     }
 
     /// <summary>
@@ -322,10 +522,21 @@ public static partial class Entrypoints
     /// <param name="simulationHandle">Handle of the simulation to which the shapes referenced by the compound children belong.</param>
     /// <param name="children">Children of the compound.</param>
     /// <param name="childMasses">Masses of the children composing the compound.</param>
+    /// <param name="inertia">Computed inertia of the compound.</param>
+    /// <returns>1 if the inertia was computed, 0 if computing failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) }, EntryPoint = "Simulation.Shapes.ComputeCompoundInertia")]
-    public static BodyInertia ComputeCompoundInertia([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("Buffer<CompoundChild>")] Buffer<CompoundChild> children, [TypeName("Buffer<float>")] Buffer<float> childMasses)
+    public static unsafe byte ComputeCompoundInertia([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("Buffer<CompoundChild>")] Buffer<CompoundChild> children, [TypeName("Buffer<float>")] Buffer<float> childMasses, BodyInertia* inertia)
     {
-        return CompoundBuilder.ComputeInertia(children, childMasses, simulations[simulationHandle].Shapes);
+        try
+        {
+            *inertia = CompoundBuilder.ComputeInertia(children, childMasses, simulations[simulationHandle].Shapes);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
@@ -335,10 +546,21 @@ public static partial class Entrypoints
     /// <param name="children">Children of the compound.</param>
     /// <param name="childMasses">Masses of the children composing the compound.</param>
     /// <param name="centerOfMass">Computed center of mass that was subtracted from the position of compound children.</param>
+    /// <param name="inertia">Computed inertia of the compound.</param>
+    /// <returns>1 if the inertia was computed, 0 if computing failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) }, EntryPoint = "Simulation.Shapes.ComputeCompoundInertiaWithRecentering")]
-    public static unsafe BodyInertia ComputeCompoundInertiaWithRecentering([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("Buffer<CompoundChild>")] Buffer<CompoundChild> children, [TypeName("Buffer<float>")] Buffer<float> childMasses, Vector3* centerOfMass)
+    public static unsafe byte ComputeCompoundInertiaWithRecentering([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("Buffer<CompoundChild>")] Buffer<CompoundChild> children, [TypeName("Buffer<float>")] Buffer<float> childMasses, Vector3* centerOfMass, BodyInertia* inertia)
     {
-        return CompoundBuilder.ComputeInertia(children, childMasses, simulations[simulationHandle].Shapes, out *centerOfMass);
+        try
+        {
+            *inertia = CompoundBuilder.ComputeInertia(children, childMasses, simulations[simulationHandle].Shapes, out *centerOfMass);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
@@ -392,11 +614,20 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to remove the shape from.</param>
     /// <param name="shape">Shape reference to request from the simulation.</param>
-    /// <returns>Pointer to the shape's data in the simulation's shapes buffers.</returns>
+    /// <returns>Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) }, EntryPoint = "Simulation.Shapes.GetSphereData")]
     public static unsafe Sphere* GetSphereShapeData([TypeName("SimulationHandle")] InstanceHandle simulationHandle, TypedIndex shape)
     {
-        return (Sphere*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<Sphere>(shape.Index));
+        try
+        {
+            CheckShape<Sphere>(simulationHandle, shape);
+            return (Sphere*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<Sphere>(shape.Index));
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return null;
+        }
     }
 
     /// <summary>
@@ -404,11 +635,20 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to remove the shape from.</param>
     /// <param name="shape">Shape reference to request from the simulation.</param>
-    /// <returns>Pointer to the shape's data in the simulation's shapes buffers.</returns>
+    /// <returns>Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.GetCapsuleData")]
     public static unsafe Capsule* GetCapsuleShapeData([TypeName("SimulationHandle")] InstanceHandle simulationHandle, TypedIndex shape)
     {
-        return (Capsule*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<Capsule>(shape.Index));
+        try
+        {
+            CheckShape<Capsule>(simulationHandle, shape);
+            return (Capsule*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<Capsule>(shape.Index));
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return null;
+        }
     }
 
     /// <summary>
@@ -416,10 +656,20 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to remove the shape from.</param>
     /// <param name="shape">Shape reference to request from the simulation.</param>
+    /// <returns>Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.GetBoxData")]
     public static unsafe Box* GetBoxShapeData([TypeName("SimulationHandle")] InstanceHandle simulationHandle, TypedIndex shape)
     {
-        return (Box*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<Box>(shape.Index));
+        try
+        {
+            CheckShape<Box>(simulationHandle, shape);
+            return (Box*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<Box>(shape.Index));
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return null;
+        }
     }
 
     /// <summary>
@@ -427,11 +677,20 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to remove the shape from.</param>
     /// <param name="shape">Shape reference to request from the simulation.</param>
-    /// <returns>Pointer to the shape's data in the simulation's shapes buffers.</returns>
+    /// <returns>Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.GetTriangleData")]
     public unsafe static Triangle* GetTriangleShapeData([TypeName("SimulationHandle")] InstanceHandle simulationHandle, TypedIndex shape)
     {
-        return (Triangle*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<Triangle>(shape.Index));
+        try
+        {
+            CheckShape<Triangle>(simulationHandle, shape);
+            return (Triangle*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<Triangle>(shape.Index));
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return null;
+        }
     }
 
     /// <summary>
@@ -439,11 +698,20 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to remove the shape from.</param>
     /// <param name="shape">Shape reference to request from the simulation.</param>
-    /// <returns>Pointer to the shape's data in the simulation's shapes buffers.</returns>
-    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.GetCapsuleCylinderData")]
+    /// <returns>Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed and an error was recorded.</returns>
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.GetCylinderData")]
     public static unsafe Cylinder* GetCylinderShapeData([TypeName("SimulationHandle")] InstanceHandle simulationHandle, TypedIndex shape)
     {
-        return (Cylinder*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<Cylinder>(shape.Index));
+        try
+        {
+            CheckShape<Cylinder>(simulationHandle, shape);
+            return (Cylinder*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<Cylinder>(shape.Index));
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return null;
+        }
     }
 
     /// <summary>
@@ -451,11 +719,20 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to remove the shape from.</param>
     /// <param name="shape">Shape reference to request from the simulation.</param>
-    /// <returns>Pointer to the shape's data in the simulation's shapes buffers.</returns>
+    /// <returns>Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) }, EntryPoint = "Simulation.Shapes.GetConvexHullData")]
     public unsafe static ConvexHull* GetConvexHullShapeData([TypeName("SimulationHandle")] InstanceHandle simulationHandle, TypedIndex shape)
     {
-        return (ConvexHull*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<ConvexHull>(shape.Index));
+        try
+        {
+            CheckShape<ConvexHull>(simulationHandle, shape);
+            return (ConvexHull*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<ConvexHull>(shape.Index));
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return null;
+        }
     }
 
     /// <summary>
@@ -463,11 +740,20 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to remove the shape from.</param>
     /// <param name="shape">Shape reference to request from the simulation.</param>
-    /// <returns>Pointer to the shape's data in the simulation's shapes buffers.</returns>
+    /// <returns>Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Shapes.GetCompoundData")]
     public static unsafe Compound* GetCompoundShapeData([TypeName("SimulationHandle")] InstanceHandle simulationHandle, TypedIndex shape)
     {
-        return (Compound*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<Compound>(shape.Index));
+        try
+        {
+            CheckShape<Compound>(simulationHandle, shape);
+            return (Compound*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<Compound>(shape.Index));
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return null;
+        }
     }
 
     /// <summary>
@@ -475,11 +761,20 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to remove the shape from.</param>
     /// <param name="shape">Shape reference to request from the simulation.</param>
-    /// <returns>Pointer to the shape's data in the simulation's shapes buffers.</returns>
+    /// <returns>Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) }, EntryPoint = "Simulation.Shapes.GetBigCompoundData")]
     public static unsafe BigCompound* GetBigCompoundShapeData([TypeName("SimulationHandle")] InstanceHandle simulationHandle, TypedIndex shape)
     {
-        return (BigCompound*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<BigCompound>(shape.Index));
+        try
+        {
+            CheckShape<BigCompound>(simulationHandle, shape);
+            return (BigCompound*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<BigCompound>(shape.Index));
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return null;
+        }
     }
 
     /// <summary>
@@ -487,10 +782,19 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to remove the shape from.</param>
     /// <param name="shape">Shape reference to request from the simulation.</param>
-    /// <returns>Pointer to the shape's data in the simulation's shapes buffers.</returns>
+    /// <returns>Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) }, EntryPoint = "Simulation.Shapes.GetMeshData")]
     public static unsafe Mesh* GetMeshShapeData([TypeName("SimulationHandle")] InstanceHandle simulationHandle, TypedIndex shape)
     {
-        return (Mesh*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<Mesh>(shape.Index));
+        try
+        {
+            CheckShape<Mesh>(simulationHandle, shape);
+            return (Mesh*)Unsafe.AsPointer(ref simulations[simulationHandle].Shapes.GetShape<Mesh>(shape.Index));
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return null;
+        }
     }
 }
@@ -6,6 +6,7 @@ using AbominationInterop;
 using BepuPhysics;
 using BepuPhysics.Collidables;
 using BepuPhysics.CollisionDetection;
+using BepuPhysics.Constraints;
 using BepuUtilities.Collections;
 using BepuUtilities.Memory;
 using Bepuvy.Callbacks;
@@ -83,10 +84,10 @@ public static partial class Entrypoints
     /// <param name="bufferPool">Buffer pool for the simulation's main allocations.</param>
     /// <param name="narrowPhaseCallbacks">Narrow phase callbacks to be invoked by the simulation.</param>
     /// <param name="poseIntegratorCallbacks">Pose integration state and callbacks to be invoked by the simulation.</param>
-    /// <param name="callback">A rust closure that captures external state to be used during integration</param>
+    /// <param name="callback">Opaque pointer passed to the pose integrator callbacks. Must stay valid until the simulation is destroyed.</param>
     /// <param name="solveDescriptionInterop">Defines velocity iteration count and substep counts for the simulation's solver.</param>
     /// <param name="initialAllocationSizes">Initial capacities to allocate within the simulation.</param>
-    /// <returns></returns>
+    /// <returns>Handle of the created simulation, or a null handle if creation failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Create")]
     [return: TypeName("SimulationHandle")]
     public static unsafe InstanceHandle CreateSimulation(
@@ -101,7 +102,10 @@ public static partial class Entrypoints
             VelocityIterationCount = solveDescriptionInterop.VelocityIterationCount,
             SubstepCount = solveDescriptionInterop.SubstepCount,
             FallbackBatchThreshold = solveDescriptionInterop.FallbackBatchThreshold,
-            VelocityIterationScheduler = solveDescriptionInterop.VelocityIterationScheduler != null ? Marshal.GetDelegateForFunctionPointer<SubstepVelocityIterationScheduler>((IntPtr)solveDescriptionInterop.VelocityIterationScheduler) : null
+            //The native scheduler takes a context, so it is wrapped instead of marshalled directly; the solver keeps the wrapper alive.
+            VelocityIterationScheduler = solveDescriptionInterop.VelocityIterationScheduler != null
+                ? new NativeVelocityIterationScheduler { Function = solveDescriptionInterop.VelocityIterationScheduler, Context = solveDescriptionInterop.VelocityIterationSchedulerContext }.Schedule
+                : null
         };
         var narrowPhaseCallbacksImpl = new NarrowPhaseCallbacks
         {
@@ -111,36 +115,86 @@ public static partial class Entrypoints
             AllowContactGenerationBetweenChildrenFunction = narrowPhaseCallbacks.AllowContactGenerationBetweenChildrenFunction,
             ConfigureConvexContactManifoldFunction = narrowPhaseCallbacks.ConfigureConvexContactManifoldFunction,
             ConfigureNonconvexContactManifoldFunction = narrowPhaseCallbacks.ConfigureNonconvexContactManifoldFunction,
-            ConfigureChildContactManifoldFunction = narrowPhaseCallbacks.ConfigureChildContactManifoldFunction
+            ConfigureChildContactManifoldFunction = narrowPhaseCallbacks.ConfigureChildContactManifoldFunction,
+            Context = narrowPhaseCallbacks.Context
         };
 
+        //The pose integrator callbacks hold on to the pointer for the lifetime of the simulation.
         RustCallback rustCallback = new RustCallback() { Callback = callback };
 
-        InstanceHandle handle = CreateSimulation(bufferPools[bufferPool], narrowPhaseCallbacksImpl, poseIntegratorCallbacks, solveDescription, initialAllocationSizes, rustCallback);
-        InstanceHandle callbackHandle = callbacks.Add(rustCallback);
-        
-        Debug.Assert(Equals(callbackHandle, handle), "The callback and simulation arrays are misaligned.");
-
-        return handle;
+        try
+        {
+            return CreateSimulation(bufferPools[bufferPool], narrowPhaseCallbacksImpl, poseIntegratorCallbacks, solveDescription, initialAllocationSizes, rustCallback);
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return default;
+        }
     }
-    
+
+    /// <summary>
+    /// Destroys a simulation and invalidates its handle.
+    /// </summary>
+    /// <param name="handle">Simulation to destroy.</param>
+    /// <returns>1 if the simulation was destroyed, 0 if destroying failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Destroy")]
-    public static  void DestroySimulation([TypeName("SimulationHandle")] InstanceHandle handle)
-    { 
-        simulations[handle].Dispose();
-        simulations.Remove(handle);
-        callbacks.Remove(handle);
+    public static byte DestroySimulation([TypeName("SimulationHandle")] InstanceHandle handle)
+    {
+        try
+        {
+            simulations[handle].Dispose();
+            simulations.Remove(handle);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
     
+     /// <summary>
+     /// Adds a body to the simulation.
+     /// </summary>
+     /// <param name="simulationHandle">Simulation to add the body to.</param>
+     /// <param name="bodyDescription">Description of the body to add.</param>
+     /// <returns>Handle of the created body, or a negative handle if adding failed and an error was recorded.</returns>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.AddBody")]
      public static BodyHandle AddBody([TypeName("SimulationHandle")] InstanceHandle simulationHandle, BodyDescription bodyDescription)
      {
-         return simulations[simulationHandle].Bodies.Add(bodyDescription);
+         try
+         {
+             CheckOptionalShape(simulationHandle, bodyDescription.Collidable.Shape);
+             return simulations[simulationHandle].Bodies.Add(bodyDescription);
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return new BodyHandle(-1);
+         }
      }
+
+     /// <summary>
+     /// Removes a body from the simulation.
+     /// </summary>
+     /// <param name="simulationHandle">Simulation to remove the body from.</param>
+     /// <param name="bodyHandle">Body to remove.</param>
+     /// <returns>1 if the body was removed, 0 if removing failed and an error was recorded.</returns>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.RemoveBody")]
-     public static unsafe void RemoveBody([TypeName("SimulationHandle")] InstanceHandle simulationHandle, BodyHandle bodyHandle)
+     public static unsafe byte RemoveBody([TypeName("SimulationHandle")] InstanceHandle simulationHandle, BodyHandle bodyHandle)
      {
-         simulations[simulationHandle].Bodies.Remove(bodyHandle);
+         try
+         {
+             CheckBody(simulationHandle, bodyHandle);
+             simulations[simulationHandle].Bodies.Remove(bodyHandle);
+             return 1;
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return 0;
+         }
      }
 
      /// <summary>
@@ -148,12 +202,21 @@ public static partial class Entrypoints
      /// </summary>
      /// <param name="simulationHandle">Simulation to pull a body's state from.</param>
      /// <param name="bodyHandle">Body handle to pull data about.</param>
-     /// <returns>Pointer to the body's dynamic state.</returns>
+     /// <returns>Pointer to the body's dynamic state, or null if the lookup failed and an error was recorded.</returns>
      /// <remarks>This is a direct pointer. The memory location associated with a body can move other bodies are removed from the simulation; do not hold a pointer beyond the point where it may be invalidated.</remarks>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.GetBodyDynamics")]
      public static unsafe BodyDynamics* GetBodyDynamics([TypeName("SimulationHandle")] InstanceHandle simulationHandle, BodyHandle bodyHandle)
      {
-         return (BodyDynamics*)Unsafe.AsPointer(ref simulations[simulationHandle].Bodies[bodyHandle].Dynamics);
+         try
+         {
+             CheckBody(simulationHandle, bodyHandle);
+             return (BodyDynamics*)Unsafe.AsPointer(ref simulations[simulationHandle].Bodies[bodyHandle].Dynamics);
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return null;
+         }
      }
 
      /// <summary>
@@ -161,12 +224,21 @@ public static partial class Entrypoints
      /// </summary>
      /// <param name="simulationHandle">Simulation to pull a body's state from.</param>
      /// <param name="bodyHandle">Body handle to pull data about.</param>
-     /// <returns>Pointer to the body's collidable.</returns>
+     /// <returns>Pointer to the body's collidable, or null if the lookup failed and an error was recorded.</returns>
      /// <remarks>This is a direct pointer. The memory location associated with a body can move if other bodies are removed from the simulation; do not hold a pointer beyond the point where it may be invalidated.</remarks>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.GetBodyCollidable")]
      public static unsafe Collidable* GetBodyCollidable([TypeName("SimulationHandle")] InstanceHandle simulationHandle, BodyHandle bodyHandle)
      {
-         return (Collidable*)Unsafe.AsPointer(ref simulations[simulationHandle].Bodies[bodyHandle].Collidable);
+         try
+         {
+             CheckBody(simulationHandle, bodyHandle);
+             return (Collidable*)Unsafe.AsPointer(ref simulations[simulationHandle].Bodies[bodyHandle].Collidable);
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return null;
+         }
      }
 
      /// <summary>
@@ -174,12 +246,21 @@ public static partial class Entrypoints
      /// </summary>
      /// <param name="simulationHandle">Simulation to pull a body's state from.</param>
      /// <param name="bodyHandle">Body handle to pull data about.</param>
-     /// <returns>Pointer to the body's activity state.</returns>
+     /// <returns>Pointer to the body's activity state, or null if the lookup failed and an error was recorded.</returns>
      /// <remarks>This is a direct pointer. The memory location associated with a body can move if other bodies are removed from the simulation; do not hold a pointer beyond the point where it may be invalidated.</remarks>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.GetBodyActivity")]
      public static unsafe BodyActivity* GetBodyActivity([TypeName("SimulationHandle")] InstanceHandle simulationHandle, BodyHandle bodyHandle)
      {
-         return (BodyActivity*)Unsafe.AsPointer(ref simulations[simulationHandle].Bodies[bodyHandle].Activity);
+         try
+         {
+             CheckBody(simulationHandle, bodyHandle);
+             return (BodyActivity*)Unsafe.AsPointer(ref simulations[simulationHandle].Bodies[bodyHandle].Activity);
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return null;
+         }
      }
 
      /// <summary>
@@ -187,13 +268,22 @@ public static partial class Entrypoints
      /// </summary>
      /// <param name="simulationHandle">Simulation to pull a body's state from.</param>
      /// <param name="bodyHandle">Body handle to pull data about.</param>
-     /// <returns>Pointer to the body's constraint list.</returns>
+     /// <returns>Pointer to the body's constraint list, or null if the lookup failed and an error was recorded.</returns>
      /// <remarks>This is a direct pointer. The memory location associated with a body can move if other bodies are removed from the simulation; do not hold a pointer beyond the point where it may be invalidated.</remarks>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.GetBodyConstraints")]
      [return: TypeName("QuickList<BodyConstraintReference>*")]
      public static unsafe QuickList<ConstraintReference>* GetBodyConstraints([TypeName("SimulationHandle")] InstanceHandle simulationHandle, BodyHandle bodyHandle)
      {
-         return (QuickList<ConstraintReference>*)Unsafe.AsPointer(ref simulations[simulationHandle].Bodies[bodyHandle].Constraints);
+         try
+         {
+             CheckBody(simulationHandle, bodyHandle);
+             return (QuickList<ConstraintReference>*)Unsafe.AsPointer(ref simulations[simulationHandle].Bodies[bodyHandle].Constraints);
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return null;
+         }
      }
 
      /// <summary>
@@ -201,11 +291,22 @@ public static partial class Entrypoints
      /// </summary>
      /// <param name="simulationHandle">Simulation to pull a body's state from.</param>
      /// <param name="bodyHandle">Body handle to pull data about.</param>
-     /// <returns>Description of a body.</returns>
+     /// <param name="description">Description of the body.</param>
+     /// <returns>1 if the description was read, 0 if the lookup failed and an error was recorded.</returns>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.GetBodyDescription")]
-     public static BodyDescription GetBodyDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, BodyHandle bodyHandle)
+     public static unsafe byte GetBodyDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, BodyHandle bodyHandle, BodyDescription* description)
      {
-         return simulations[simulationHandle].Bodies.GetDescription(bodyHandle);
+         try
+         {
+             CheckBody(simulationHandle, bodyHandle);
+             *description = simulations[simulationHandle].Bodies.GetDescription(bodyHandle);
+             return 1;
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return 0;
+         }
      }
 
      /// <summary>
@@ -214,33 +315,112 @@ public static partial class Entrypoints
      /// <param name="simulationHandle">Simulation to pull a body's state from.</param>
      /// <param name="bodyHandle">Body handle to pull data about.</param>
      /// <param name="description">Description to apply to the body.</param>
+     /// <returns>1 if the description was applied, 0 if applying failed and an error was recorded.</returns>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.ApplyBodyDescription")]
-     public static  void ApplyBodyDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, BodyHandle bodyHandle, BodyDescription description)
+     public static byte ApplyBodyDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, BodyHandle bodyHandle, BodyDescription description)
      {
-         simulations[simulationHandle].Bodies.ApplyDescription(bodyHandle, description);
+         try
+         {
+             CheckBody(simulationHandle, bodyHandle);
+             CheckOptionalShape(simulationHandle, description.Collidable.Shape);
+             simulations[simulationHandle].Bodies.ApplyDescription(bodyHandle, description);
+             return 1;
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return 0;
+         }
      }
 
+     /// <summary>
+     /// Sets the local inertia of a body.
+     /// </summary>
+     /// <param name="simulationHandle">Simulation containing the body.</param>
+     /// <param name="bodyHandle">Body handle to set the inertia of.</param>
+     /// <param name="localInertia">New inertia of the body in its local space.</param>
+     /// <returns>1 if the inertia was set, 0 if setting it failed and an error was recorded.</returns>
+     /// <remarks>Wakes the body. Changing between zero and nonzero inverse mass moves the body between the kinematic and dynamic states,
+     /// which has to update the solver and island bookkeeping; writing the inertia directly skips that.</remarks>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.SetBodyLocalInertia")]
+     public static byte SetBodyLocalInertia([TypeName("SimulationHandle")] InstanceHandle simulationHandle, BodyHandle bodyHandle, BodyInertia localInertia)
+     {
+         try
+         {
+             CheckBody(simulationHandle, bodyHandle);
+             simulations[simulationHandle].Bodies[bodyHandle].SetLocalInertia(localInertia);
+             return 1;
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return 0;
+         }
+     }
+
+     /// <summary>
+     /// Adds a static to the simulation.
+     /// </summary>
+     /// <param name="simulationHandle">Simulation to add the static to.</param>
+     /// <param name="staticDescription">Description of the static to add.</param>
+     /// <returns>Handle of the created static, or a negative handle if adding failed and an error was recorded.</returns>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.AddStatic")]
-     public static  StaticHandle AddStatic([TypeName("SimulationHandle")] InstanceHandle simulationHandle, StaticDescription staticDescription)
+     public static StaticHandle AddStatic([TypeName("SimulationHandle")] InstanceHandle simulationHandle, StaticDescription staticDescription)
      {
-         return simulations[simulationHandle].Statics.Add(staticDescription);
+         try
+         {
+             CheckShape(simulationHandle, staticDescription.Shape);
+             return simulations[simulationHandle].Statics.Add(staticDescription);
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return new StaticHandle(-1);
+         }
      }
+
+     /// <summary>
+     /// Removes a static from the simulation.
+     /// </summary>
+     /// <param name="simulationHandle">Simulation to remove the static from.</param>
+     /// <param name="staticHandle">Static to remove.</param>
+     /// <returns>1 if the static was removed, 0 if removing failed and an error was recorded.</returns>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.RemoveStatic")]
-     public static  void RemoveStatic([TypeName("SimulationHandle")] InstanceHandle simulationHandle, StaticHandle staticHandle)
+     public static byte RemoveStatic([TypeName("SimulationHandle")] InstanceHandle simulationHandle, StaticHandle staticHandle)
      {
-         simulations[simulationHandle].Statics.Remove(staticHandle);
+         try
+         {
+             CheckStatic(simulationHandle, staticHandle);
+             simulations[simulationHandle].Statics.Remove(staticHandle);
+             return 1;
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return 0;
+         }
      }
+
      /// <summary>
      /// Gets a pointer to data associated with a static.
      /// </summary>
      /// <param name="simulationHandle">Simulation to pull a static's state from.</param>
      /// <param name="staticHandle">Static handle to pull data about.</param>
-     /// <returns>Pointer to the static's data.</returns>
+     /// <returns>Pointer to the static's data, or null if the lookup failed and an error was recorded.</returns>
      /// <remarks>This is a direct pointer. The memory location associated with a static can move if other statics are removed from the simulation; do not hold a pointer beyond the point where it may be invalidated.</remarks>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.GetStatic")]
      public static unsafe Static* GetStatic([TypeName("SimulationHandle")] InstanceHandle simulationHandle, StaticHandle staticHandle)
      {
-         return (Static*)Unsafe.AsPointer(ref simulations[simulationHandle].Statics.GetDirectReference(staticHandle));
+         try
+         {
+             CheckStatic(simulationHandle, staticHandle);
+             return (Static*)Unsafe.AsPointer(ref simulations[simulationHandle].Statics.GetDirectReference(staticHandle));
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return null;
+         }
      }
 
      /// <summary>
@@ -248,11 +428,22 @@ public static partial class Entrypoints
      /// </summary>
      /// <param name="simulationHandle">Simulation to pull a static's state from.</param>
      /// <param name="staticHandle">Static handle to pull data about.</param>
-     /// <returns>Description of the static..</returns>
+     /// <param name="description">Description of the static.</param>
+     /// <returns>1 if the description was read, 0 if the lookup failed and an error was recorded.</returns>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.GetStaticDescription")]
-     public static StaticDescription GetStaticDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, StaticHandle staticHandle)
+     public static unsafe byte GetStaticDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, StaticHandle staticHandle, StaticDescription* description)
      {
-         return simulations[simulationHandle].Statics.GetDescription(staticHandle);
+         try
+         {
+             CheckStatic(simulationHandle, staticHandle);
+             *description = simulations[simulationHandle].Statics.GetDescription(staticHandle);
+             return 1;
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return 0;
+         }
      }
 
      /// <summary>
@@ -261,10 +452,22 @@ public static partial class Entrypoints
      /// <param name="simulationHandle">Simulation to pull a static's state from.</param>
      /// <param name="staticHandle">Static handle to pull data about.</param>
      /// <param name="description">The description to be applied to the static.</param>
+     /// <returns>1 if the description was applied, 0 if applying failed and an error was recorded.</returns>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.ApplyStaticDescription")]
-     public static void ApplyStaticDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, StaticHandle staticHandle, StaticDescription description)
+     public static byte ApplyStaticDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, StaticHandle staticHandle, StaticDescription description)
      {
-         simulations[simulationHandle].Statics.ApplyDescription(staticHandle, description);
+         try
+         {
+             CheckStatic(simulationHandle, staticHandle);
+             CheckShape(simulationHandle, description.Shape);
+             simulations[simulationHandle].Statics.ApplyDescription(staticHandle, description);
+             return 1;
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return 0;
+         }
      }
 
     /// <summary>
@@ -272,18 +475,23 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="simulationHandle">Handle of the simulation to step.</param>
     /// <param name="dt">Duration of the timestep.</param>
-    /// <param name="callback"></param>
     /// <param name="threadDispatcherHandle">Handle of the thread dispatcher to use, if any. Can be a null reference.</param>
+    /// <returns>1 if the step completed, 0 if it failed and an error was recorded.</returns>
+    /// <remarks>The pose integrator callbacks are passed the pointer given when the simulation was created.</remarks>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Timestep")]
-     public static unsafe void Timestep([TypeName("SimulationHandle")] InstanceHandle simulationHandle, float dt, void* callback, [TypeName("ThreadDispatcherHandle")] InstanceHandle threadDispatcherHandle = new())
+     public static unsafe byte Timestep([TypeName("SimulationHandle")] InstanceHandle simulationHandle, float dt, [TypeName("ThreadDispatcherHandle")] InstanceHandle threadDispatcherHandle = new())
      {
-         var threadDispatcher = threadDispatcherHandle.Null ? null : threadDispatchers[threadDispatcherHandle];
-        
-         //If callback captures rust state, it will exist on the stack.
-         //Since this is a stack ptr, it will change each timestep hence it must be updated.
-         callbacks[simulationHandle].Callback = callback;
-         
-         simulations[simulationHandle].Timestep(dt, threadDispatcher);
+         try
+         {
+             var threadDispatcher = threadDispatcherHandle.Null ? null : threadDispatchers[threadDispatcherHandle];
+             simulations[simulationHandle].Timestep(dt, threadDispatcher);
+             return 1;
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return 0;
+         }
      }
 
      /// <summary>
@@ -293,12 +501,23 @@ public static partial class Entrypoints
      /// <param name="bodyHandle">Body to pull bounding box data about.</param>
      /// <param name="min">Minimum bounds of the collidable's bounding box.</param>
      /// <param name="max">Maximum bounds of the collidable's bounding box.</param>
+     /// <returns>1 if the bounding box was read, 0 if the lookup failed and an error was recorded.</returns>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.GetBodyBoundingBoxInBroadPhase")]
-     public static unsafe void GetBodyBoundingBoxInBroadPhase([TypeName("SimulationHandle")] InstanceHandle simulationHandle, BodyHandle bodyHandle, Vector3* min, Vector3* max)
+     public static unsafe byte GetBodyBoundingBoxInBroadPhase([TypeName("SimulationHandle")] InstanceHandle simulationHandle, BodyHandle bodyHandle, Vector3* min, Vector3* max)
      {
-         simulations[simulationHandle].Bodies[bodyHandle].GetBoundsReferencesFromBroadPhase(out var minPointer, out var maxPointer);
-         *min = *minPointer;
-         *max = *maxPointer;
+         try
+         {
+             CheckBody(simulationHandle, bodyHandle);
+             simulations[simulationHandle].Bodies[bodyHandle].GetBoundsReferencesFromBroadPhase(out var minPointer, out var maxPointer);
+             *min = *minPointer;
+             *max = *maxPointer;
+             return 1;
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return 0;
+         }
      }
 
      /// <summary>
@@ -308,12 +527,23 @@ public static partial class Entrypoints
      /// <param name="staticHandle">Static to pull bounding box data about.</param>
      /// <param name="min">Minimum bounds of the collidable's bounding box.</param>
      /// <param name="max">Maximum bounds of the collidable's bounding box.</param>
+     /// <returns>1 if the bounding box was read, 0 if the lookup failed and an error was recorded.</returns>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.GetStaticBoundingBoxInBroadPhase")]
-     public static unsafe void GetStaticBoundingBoxInBroadPhase([TypeName("SimulationHandle")] InstanceHandle simulationHandle, StaticHandle staticHandle, Vector3* min, Vector3* max)
+     public static unsafe byte GetStaticBoundingBoxInBroadPhase([TypeName("SimulationHandle")] InstanceHandle simulationHandle, StaticHandle staticHandle, Vector3* min, Vector3* max)
      {
-         simulations[simulationHandle].Statics[staticHandle].GetBoundsReferencesFromBroadPhase(out var minPointer, out var maxPointer);
-         *min = *minPointer;
-         *max = *maxPointer;
+         try
+         {
+             CheckStatic(simulationHandle, staticHandle);
+             simulations[simulationHandle].Statics[staticHandle].GetBoundsReferencesFromBroadPhase(out var minPointer, out var maxPointer);
+             *min = *minPointer;
+             *max = *maxPointer;
+             return 1;
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return 0;
+         }
      }
 
      /// <summary>
@@ -321,11 +551,21 @@ public static partial class Entrypoints
      /// </summary>
      /// <param name="simulationHandle">Handle of the simulation to pull data from.</param>
      /// <param name="bodyHandleToIndexMapping">Mapping from a body handle to the body's memory location.</param>
+     /// <returns>1 if the mapping was read, 0 if the lookup failed and an error was recorded.</returns>
      /// <remarks>The buffer returned by this function can be invalidated if the simulation resizes it.</remarks>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.GetBodyHandleToLocationMappings")]
-     public static unsafe void GetBodyHandleToLocationMapping([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("Buffer<BodyMemoryLocation>*")] Buffer<BodyMemoryLocation>* bodyHandleToIndexMapping)
+     public static unsafe byte GetBodyHandleToLocationMapping([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("Buffer<BodyMemoryLocation>*")] Buffer<BodyMemoryLocation>* bodyHandleToIndexMapping)
      {
-         *bodyHandleToIndexMapping = simulations[simulationHandle].Bodies.HandleToLocation;
+         try
+         {
+             *bodyHandleToIndexMapping = simulations[simulationHandle].Bodies.HandleToLocation;
+             return 1;
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return 0;
+         }
      }
 
      /// <summary>
@@ -333,11 +573,21 @@ public static partial class Entrypoints
      /// </summary>
      /// <param name="simulationHandle">Handle of the simulation to pull data from.</param>
      /// <param name="bodySets">Mapping from a body handle to the body's memory location.</param>
+     /// <returns>1 if the body sets were read, 0 if the lookup failed and an error was recorded.</returns>
      /// <remarks>The buffer returned by this function can be invalidated if the simulation resizes it.</remarks>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.GetBodySets")]
-     public static unsafe void GetBodySets([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("Buffer<BodySet>*")] Buffer<BodySet>* bodySets)
+     public static unsafe byte GetBodySets([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("Buffer<BodySet>*")] Buffer<BodySet>* bodySets)
      {
-         *bodySets = simulations[simulationHandle].Bodies.Sets;
+         try
+         {
+             *bodySets = simulations[simulationHandle].Bodies.Sets;
+             return 1;
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return 0;
+         }
      }
 
      /// <summary>
@@ -345,11 +595,21 @@ public static partial class Entrypoints
      /// </summary>
      /// <param name="simulationHandle">Handle of the simulation to pull data from.</param>
      /// <param name="staticHandleToIndexMapping">Mapping from a static handle to the static's memory location.</param>
+     /// <returns>1 if the mapping was read, 0 if the lookup failed and an error was recorded.</returns>
      /// <remarks>The buffer returned by this function can be invalidated if the simulation resizes it.</remarks>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.GetStaticHandleToLocationMapping")]
-     public static unsafe void GetStaticHandleToLocationMapping([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("Buffer<int32_t>*")] Buffer<int>* staticHandleToIndexMapping)
+     public static unsafe byte GetStaticHandleToLocationMapping([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("Buffer<int32_t>*")] Buffer<int>* staticHandleToIndexMapping)
      {
-         *staticHandleToIndexMapping = simulations[simulationHandle].Statics.HandleToIndex;
+         try
+         {
+             *staticHandleToIndexMapping = simulations[simulationHandle].Statics.HandleToIndex;
+             return 1;
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return 0;
+         }
      }
 
      /// <summary>
@@ -358,11 +618,464 @@ public static partial class Entrypoints
      /// <param name="simulationHandle">Handle of the simulation to pull data from.</param>
      /// <param name="statics">The set of all statics within a simulation.</param>
      /// <param name="count">Number of statics in the simulation.</param>
+     /// <returns>1 if the statics were read, 0 if the lookup failed and an error was recorded.</returns>
      /// <remarks>The buffer returned by this function can be invalidated if the simulation resizes it. The count is a snapshot.</remarks>
      [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.GetStatics")]
-     public static unsafe void GetStatics([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("Buffer<Static>*")] Buffer<Static>* statics, [TypeName("int32_t*")] int* count)
+     public static unsafe byte GetStatics([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("Buffer<Static>*")] Buffer<Static>* statics, [TypeName("int32_t*")] int* count)
+     {
+         try
+         {
+             *statics = simulations[simulationHandle].Statics.StaticsBuffer;
+             *count = simulations[simulationHandle].Statics.Count;
+             return 1;
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return 0;
+         }
+     }
+
+     //Solver.Add and Solver.ApplyDescription are generic over the description type, so each supported description gets a thin entrypoint over these.
+     //Adding returns a negative handle and applying returns 0 if it failed and an error was recorded.
+     private static unsafe ConstraintHandle AddConstraint<TDescription>(InstanceHandle simulationHandle, BodyHandle* bodyHandles, int bodyCount, in TDescription description)
+         where TDescription : unmanaged, IConstraintDescription<TDescription>
+     {
+         try
+         {
+             CheckBodies(simulationHandle, bodyHandles, bodyCount);
+             return simulations[simulationHandle].Solver.Add(new Span<BodyHandle>(bodyHandles, bodyCount), description);
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return new ConstraintHandle(-1);
+         }
+     }
+
+     private static byte ApplyConstraintDescription<TDescription>(InstanceHandle simulationHandle, ConstraintHandle constraintHandle, in TDescription description)
+         where TDescription : unmanaged, IConstraintDescription<TDescription>
+     {
+         try
+         {
+             var solver = simulations[simulationHandle].Solver;
+             //The solver only asserts on type mismatches, and a mismatched description would be written over the wrong constraint layout.
+             if (solver.HandleToConstraint[constraintHandle.Value].TypeId != TDescription.ConstraintTypeId)
+             {
+                 SetLastError(new InvalidHandleException($"Constraint handle {constraintHandle.Value} does not refer to a {typeof(TDescription).Name} constraint."));
+                 return 0;
+             }
+             solver.ApplyDescription(constraintHandle, description);
+             return 1;
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return 0;
+         }
+     }
+
+     /// <summary>
+     /// Removes a constraint from the simulation.
+     /// </summary>
+     /// <param name="simulationHandle">Simulation to remove the constraint from.</param>
+     /// <param name="constraintHandle">Handle of the constraint to remove.</param>
+     /// <returns>1 if the constraint was removed, 0 if removing failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.Remove")]
+     public static byte RemoveConstraint([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle)
+     {
+         try
+         {
+             simulations[simulationHandle].Solver.Remove(constraintHandle);
+             return 1;
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return 0;
+         }
+     }
+
+     /// <summary>
+     /// Checks whether a constraint handle refers to a constraint that currently exists in the simulation.
+     /// </summary>
+     /// <param name="simulationHandle">Simulation to check.</param>
+     /// <param name="constraintHandle">Handle of the constraint to look for.</param>
+     /// <param name="exists">1 if the constraint exists, 0 otherwise.</param>
+     /// <returns>1 if the check was performed, 0 if the simulation lookup failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.Exists")]
+     public static unsafe byte ConstraintExists([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, byte* exists)
+     {
+         try
+         {
+             *exists = simulations[simulationHandle].Solver.ConstraintExists(constraintHandle) ? (byte)1 : (byte)0;
+             return 1;
+         }
+         catch (Exception exception)
+         {
+             SetLastError(exception);
+             return 0;
+         }
+     }
+
+     /// <summary>
+     /// Adds a ball socket to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddBallSocket")]
+     public static unsafe ConstraintHandle AddBallSocket([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, BallSocket description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing ball socket.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not a ball socket or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplyBallSocketDescription")]
+     public static byte ApplyBallSocketDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, BallSocket description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds a hinge to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddHinge")]
+     public static unsafe ConstraintHandle AddHinge([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, Hinge description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing hinge.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not a hinge or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplyHingeDescription")]
+     public static byte ApplyHingeDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, Hinge description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds a weld to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddWeld")]
+     public static unsafe ConstraintHandle AddWeld([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, Weld description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing weld.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not a weld or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplyWeldDescription")]
+     public static byte ApplyWeldDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, Weld description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds a swivel hinge to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddSwivelHinge")]
+     public static unsafe ConstraintHandle AddSwivelHinge([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, SwivelHinge description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing swivel hinge.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not a swivel hinge or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplySwivelHingeDescription")]
+     public static byte ApplySwivelHingeDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, SwivelHinge description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds an angular hinge to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddAngularHinge")]
+     public static unsafe ConstraintHandle AddAngularHinge([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, AngularHinge description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing angular hinge.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not an angular hinge or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplyAngularHingeDescription")]
+     public static byte ApplyAngularHingeDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, AngularHinge description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds a point on line servo to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddPointOnLineServo")]
+     public static unsafe ConstraintHandle AddPointOnLineServo([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, PointOnLineServo description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing point on line servo.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not a point on line servo or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplyPointOnLineServoDescription")]
+     public static byte ApplyPointOnLineServoDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, PointOnLineServo description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds a center distance constraint to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddCenterDistanceConstraint")]
+     public static unsafe ConstraintHandle AddCenterDistanceConstraint([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, CenterDistanceConstraint description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing center distance constraint.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not a center distance constraint or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplyCenterDistanceConstraintDescription")]
+     public static byte ApplyCenterDistanceConstraintDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, CenterDistanceConstraint description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds an angular motor to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddAngularMotor")]
+     public static unsafe ConstraintHandle AddAngularMotor([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, AngularMotor description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing angular motor.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not an angular motor or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplyAngularMotorDescription")]
+     public static byte ApplyAngularMotorDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, AngularMotor description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds a linear axis motor to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddLinearAxisMotor")]
+     public static unsafe ConstraintHandle AddLinearAxisMotor([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, LinearAxisMotor description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing linear axis motor.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not a linear axis motor or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplyLinearAxisMotorDescription")]
+     public static byte ApplyLinearAxisMotorDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, LinearAxisMotor description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds a twist motor to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddTwistMotor")]
+     public static unsafe ConstraintHandle AddTwistMotor([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, TwistMotor description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing twist motor.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not a twist motor or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplyTwistMotorDescription")]
+     public static byte ApplyTwistMotorDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, TwistMotor description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds an angular servo to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddAngularServo")]
+     public static unsafe ConstraintHandle AddAngularServo([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, AngularServo description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing angular servo.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not an angular servo or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplyAngularServoDescription")]
+     public static byte ApplyAngularServoDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, AngularServo description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds a linear axis servo to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddLinearAxisServo")]
+     public static unsafe ConstraintHandle AddLinearAxisServo([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, LinearAxisServo description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing linear axis servo.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not a linear axis servo or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplyLinearAxisServoDescription")]
+     public static byte ApplyLinearAxisServoDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, LinearAxisServo description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds an one body linear servo to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddOneBodyLinearServo")]
+     public static unsafe ConstraintHandle AddOneBodyLinearServo([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, OneBodyLinearServo description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing one body linear servo.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not an one body linear servo or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplyOneBodyLinearServoDescription")]
+     public static byte ApplyOneBodyLinearServoDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, OneBodyLinearServo description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds an one body angular servo to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddOneBodyAngularServo")]
+     public static unsafe ConstraintHandle AddOneBodyAngularServo([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, OneBodyAngularServo description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing one body angular servo.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not an one body angular servo or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplyOneBodyAngularServoDescription")]
+     public static byte ApplyOneBodyAngularServoDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, OneBodyAngularServo description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds a twist servo to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddTwistServo")]
+     public static unsafe ConstraintHandle AddTwistServo([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, TwistServo description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing twist servo.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not a twist servo or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplyTwistServoDescription")]
+     public static byte ApplyTwistServoDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, TwistServo description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds a twist limit to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddTwistLimit")]
+     public static unsafe ConstraintHandle AddTwistLimit([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, TwistLimit description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing twist limit.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not a twist limit or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplyTwistLimitDescription")]
+     public static byte ApplyTwistLimitDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, TwistLimit description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds a swing limit to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddSwingLimit")]
+     public static unsafe ConstraintHandle AddSwingLimit([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, SwingLimit description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing swing limit.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not a swing limit or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplySwingLimitDescription")]
+     public static byte ApplySwingLimitDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, SwingLimit description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds a distance limit to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddDistanceLimit")]
+     public static unsafe ConstraintHandle AddDistanceLimit([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, DistanceLimit description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing distance limit.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not a distance limit or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplyDistanceLimitDescription")]
+     public static byte ApplyDistanceLimitDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, DistanceLimit description)
+     {
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
+     }
+
+     /// <summary>
+     /// Adds a linear axis limit to the simulation.
+     /// </summary>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.AddLinearAxisLimit")]
+     public static unsafe ConstraintHandle AddLinearAxisLimit([TypeName("SimulationHandle")] InstanceHandle simulationHandle, [TypeName("BodyHandle*")] BodyHandle* bodyHandles, int bodyCount, LinearAxisLimit description)
+     {
+         return AddConstraint(simulationHandle, bodyHandles, bodyCount, description);
+     }
+
+     /// <summary>
+     /// Applies a description to an existing linear axis limit.
+     /// </summary>
+     /// <returns>1 if the description was applied, 0 if the constraint is not a linear axis limit or applying failed and an error was recorded.</returns>
+     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Simulation.Constraints.ApplyLinearAxisLimitDescription")]
+     public static byte ApplyLinearAxisLimitDescription([TypeName("SimulationHandle")] InstanceHandle simulationHandle, ConstraintHandle constraintHandle, LinearAxisLimit description)
      {
-         *statics = simulations[simulationHandle].Statics.StaticsBuffer;
-         *count = simulations[simulationHandle].Statics.Count;
+         return ApplyConstraintDescription(simulationHandle, constraintHandle, description);
      }
 }
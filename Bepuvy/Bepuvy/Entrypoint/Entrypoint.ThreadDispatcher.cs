@@ -11,32 +11,83 @@ public static partial class Entrypoints
     /// </summary>
     /// <param name="threadCount">Number of threads to use within the thread dispatcher.</param>
     /// <param name="threadPoolAllocationBlockSize">Minimum size in bytes of blocks allocated in per-thread buffer pools. Allocations requiring more space can result in larger block sizes, but no pools will allocate smaller blocks.</param>
+    /// <returns>Handle of the created dispatcher, or a null handle if creation failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "ThreadDispatcher.Create")]
     [return: TypeName("ThreadDispatcherHandle")]
     public static InstanceHandle CreateThreadDispatcher(int threadCount, int threadPoolAllocationBlockSize = 16384)
     {
-        return threadDispatchers.Add(new ThreadDispatcher(threadCount, threadPoolAllocationBlockSize));
+        try
+        {
+            return threadDispatchers.Add(new ThreadDispatcher(threadCount, threadPoolAllocationBlockSize));
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return default;
+        }
+    }
+
+    /// <summary>
+    /// Creates a new thread dispatcher whose work is scheduled by a native job system.
+    /// </summary>
+    /// <param name="threadCount">Maximum number of workers the native job system can run concurrently.</param>
+    /// <param name="threadPoolAllocationBlockSize">Minimum size in bytes of blocks allocated in per-thread buffer pools. Allocations requiring more space can result in larger block sizes, but no pools will allocate smaller blocks.</param>
+    /// <param name="context">Opaque pointer passed back to the dispatch function.</param>
+    /// <param name="dispatchFunction">Runs the given worker body once for every worker index below the worker count, concurrently, and returns once all have completed.</param>
+    /// <returns>Handle of the created dispatcher, or a null handle if creation failed and an error was recorded.</returns>
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "ThreadDispatcher.CreateCustom")]
+    [return: TypeName("ThreadDispatcherHandle")]
+    public static unsafe InstanceHandle CreateCustomThreadDispatcher(int threadCount, int threadPoolAllocationBlockSize, void* context, delegate* unmanaged<void*, int, delegate* unmanaged<void*, int, void>, void*, void> dispatchFunction)
+    {
+        try
+        {
+            return threadDispatchers.Add(new RustThreadDispatcher(threadCount, threadPoolAllocationBlockSize, context, dispatchFunction));
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return default;
+        }
     }
 
     /// <summary>
     /// Releases all resources held by a thread dispatcher and invalidates its handle.
     /// </summary>
     /// <param name="handle">Thread dispatcher to destroy.</param>
-    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "ThreadDisaptcher.Destroy")]
-    public static void DestroyThreadDispatcher([TypeName("ThreadDispatcherHandle")] InstanceHandle handle)
+    /// <returns>1 if the dispatcher was destroyed, 0 if destroying failed and an error was recorded.</returns>
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "ThreadDispatcher.Destroy")]
+    public static byte DestroyThreadDispatcher([TypeName("ThreadDispatcherHandle")] InstanceHandle handle)
     {
-        threadDispatchers[handle].Dispose();
-        threadDispatchers.Remove(handle);
+        try
+        {
+            ((IDisposable)threadDispatchers[handle]).Dispose();
+            threadDispatchers.Remove(handle);
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
     }
 
     /// <summary>
-    /// Releases all resources held by a thread dispatcher and invalidates its handle.
+    /// Gets the number of worker threads owned by a thread dispatcher.
     /// </summary>
     /// <param name="handle">Thread dispatcher to check the thread count of.</param>
+    /// <returns>Number of threads in the dispatcher, or -1 if the lookup failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "ThreadDispatcher.GetThreadCount")]
     public static int GetThreadCount([TypeName("ThreadDispatcherHandle")] InstanceHandle handle)
     {
-        return threadDispatchers[handle].ThreadCount;
+        try
+        {
+            return threadDispatchers[handle].ThreadCount;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return -1;
+        }
     }
 
 
@@ -45,16 +96,49 @@ public static partial class Entrypoints
     /// Includes allocated memory regardless of whether it currently has outstanding references.
     /// </summary>
     /// <param name="threadDispatcherHandle">Thread dispatcher to check allocations for.</param>
-    /// <returns>Total number of bytes allocated from native memory in this thread dispatcher's per-thread pool.</returns>
+    /// <param name="size">Total number of bytes allocated from native memory in this thread dispatcher's per-thread pool.</param>
+    /// <returns>1 if the size was computed, 0 if the lookup failed and an error was recorded.</returns>
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "ThreadDispatcher.GetAllocatedMemorySize")]
-    public static ulong GetAllocatedMemorySizeInThreadDispatcher([TypeName("ThreadDispatcherHandle")] InstanceHandle threadDispatcherHandle)
+    public unsafe static byte GetAllocatedMemorySizeInThreadDispatcher([TypeName("ThreadDispatcherHandle")] InstanceHandle threadDispatcherHandle, ulong* size)
+    {
+        try
+        {
+            ulong sum = 0;
+            var dispatcher = threadDispatchers[threadDispatcherHandle];
+            for (var i = 0; i < dispatcher.ThreadCount; ++i)
+            {
+                sum += dispatcher.WorkerPools[i].GetTotalAllocatedByteCount();
+            }
+            *size = sum;
+            return 1;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
+    }
+
+    /// <summary>
+    /// Computes the number of bytes allocated from native memory in a single worker's pool of a dispatcher.
+    /// Includes allocated memory regardless of whether it currently has outstanding references.
+    /// </summary>
+    /// <param name="threadDispatcherHandle">Thread dispatcher to check allocations for.</param>
+    /// <param name="workerIndex">Index of the worker whose pool should be measured.</param>
+    /// <param name="size">Total number of bytes allocated from native memory in the worker's pool.</param>
+    /// <returns>1 if the size was computed, 0 if the lookup failed and an error was recorded.</returns>
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "ThreadDispatcher.GetWorkerAllocatedMemorySize")]
+    public unsafe static byte GetWorkerAllocatedMemorySize([TypeName("ThreadDispatcherHandle")] InstanceHandle threadDispatcherHandle, int workerIndex, ulong* size)
     {
-        ulong sum = 0;
-        var dispatcher = threadDispatchers[threadDispatcherHandle];
-        for (var i = 0; i < dispatcher.ThreadCount; ++i)
+        try
+        {
+            *size = threadDispatchers[threadDispatcherHandle].WorkerPools[workerIndex].GetTotalAllocatedByteCount();
+            return 1;
+        }
+        catch (Exception exception)
         {
-            sum += dispatcher.WorkerPools[i].GetTotalAllocatedByteCount();
+            SetLastError(exception);
+            return 0;
         }
-        return sum;
     }
 }
@@ -0,0 +1,115 @@
+using System.Diagnostics;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using BepuPhysics;
+using BepuPhysics.Collidables;
+
+namespace Bepuvy;
+
+//Handle checks compiled into entrypoints when the library is built with CheckedHandles=true.
+//A failed check throws an InvalidHandleException, as does an instance directory lookup with a bad handle.
+//Every entrypoint that takes a handle catches exceptions and records them, so a failed check is reported to the native side as an invalid handle error.
+public static partial class Entrypoints
+{
+    /// <summary>
+    /// Gets why a shape index can't be used with a simulation.
+    /// </summary>
+    /// <returns>Description of the problem, or null if the index points into a registered shape batch.</returns>
+    /// <remarks>Shape batches don't expose which of their slots are free, so an index to a removed shape within the batch's capacity is not detected.</remarks>
+    private static string? GetShapeError(Simulation simulation, TypedIndex shape)
+    {
+        if (!shape.Exists)
+            return "Shape index does not refer to any shape.";
+        var shapes = simulation.Shapes;
+        if (shape.Type >= shapes.RegisteredTypeSpan || shapes[shape.Type] == null)
+            return $"Shape type {shape.Type} is not registered in the simulation.";
+        if (shape.Index >= shapes[shape.Type].Capacity)
+            return $"Shape index {shape.Index} is outside of the batch for shape type {shape.Type}.";
+        return null;
+    }
+
+    [Conditional("CHECKED_HANDLES")]
+    private static void CheckBody(InstanceHandle simulationHandle, BodyHandle body)
+    {
+        if (!simulations[simulationHandle].Bodies.BodyExists(body))
+            throw new InvalidHandleException($"Body handle {body.Value} does not exist in the simulation.");
+    }
+
+    [Conditional("CHECKED_HANDLES")]
+    private static unsafe void CheckBodies(InstanceHandle simulationHandle, BodyHandle* bodies, int bodyCount)
+    {
+        for (int i = 0; i < bodyCount; ++i)
+            CheckBody(simulationHandle, bodies[i]);
+    }
+
+    [Conditional("CHECKED_HANDLES")]
+    private static void CheckStatic(InstanceHandle simulationHandle, StaticHandle handle)
+    {
+        if (!simulations[simulationHandle].Statics.StaticExists(handle))
+            throw new InvalidHandleException($"Static handle {handle.Value} does not exist in the simulation.");
+    }
+
+    [Conditional("CHECKED_HANDLES")]
+    private static void CheckShape(InstanceHandle simulationHandle, TypedIndex shape)
+    {
+        var error = GetShapeError(simulations[simulationHandle], shape);
+        if (error != null)
+            throw new InvalidHandleException(error);
+    }
+
+    /// <summary>
+    /// Checks a shape index that is allowed to refer to no shape at all, like the shape of a body's collidable.
+    /// </summary>
+    [Conditional("CHECKED_HANDLES")]
+    private static void CheckOptionalShape(InstanceHandle simulationHandle, TypedIndex shape)
+    {
+        if (shape.Exists)
+            CheckShape(simulationHandle, shape);
+    }
+
+    [Conditional("CHECKED_HANDLES")]
+    private static void CheckShape<TShape>(InstanceHandle simulationHandle, TypedIndex shape) where TShape : unmanaged, IShape
+    {
+        CheckShape(simulationHandle, shape);
+        if (simulations[simulationHandle].Shapes[shape.Type] is not ShapeBatch<TShape>)
+            throw new InvalidHandleException($"Shape type {shape.Type} does not hold shapes of type {typeof(TShape).Name}.");
+    }
+
+    /// <summary>
+    /// Gets whether the library was built with handle checks in its entrypoints.
+    /// </summary>
+    /// <returns>1 if handles are checked, 0 otherwise.</returns>
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Validation.IsEnabled")]
+    public static byte IsValidationEnabled()
+    {
+#if CHECKED_HANDLES
+        return 1;
+#else
+        return 0;
+#endif
+    }
+
+    /// <summary>
+    /// Checks whether a shape index can be used with a simulation. Available whether or not the library was built with handle checks.
+    /// </summary>
+    /// <param name="simulationHandle">Simulation the shape is expected to live in.</param>
+    /// <param name="shape">Shape index to check.</param>
+    /// <returns>1 if the index points into a registered shape batch, 0 if it doesn't and an error was recorded.</returns>
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)], EntryPoint = "Validation.ValidateShape")]
+    public static byte ValidateShape([TypeName("SimulationHandle")] InstanceHandle simulationHandle, TypedIndex shape)
+    {
+        try
+        {
+            var error = GetShapeError(simulations[simulationHandle], shape);
+            if (error == null)
+                return 1;
+            SetLastError(new InvalidHandleException(error));
+            return 0;
+        }
+        catch (Exception exception)
+        {
+            SetLastError(exception);
+            return 0;
+        }
+    }
+}
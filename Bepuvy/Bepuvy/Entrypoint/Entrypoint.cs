@@ -36,8 +36,7 @@ public static partial class Entrypoints
 {
     static InstanceDirectory<BufferPool> bufferPools = new InstanceDirectory<BufferPool>(0);
     static InstanceDirectory<Simulation> simulations = new InstanceDirectory<Simulation>(1);
-    static InstanceDirectory<ThreadDispatcher> threadDispatchers = new InstanceDirectory<ThreadDispatcher>(2);
-    static InstanceDirectory<RustCallback> callbacks = new InstanceDirectory<RustCallback>(3);
+    static InstanceDirectory<IThreadDispatcher> threadDispatchers = new InstanceDirectory<IThreadDispatcher>(2);
 
     /// <summary>
     /// Gets the number of threads exposed by the operating system on this platform. Cores with SMT can show as having multiple threads.
@@ -23,12 +23,15 @@ public class InstanceDirectory<T> where T : class, IDisposable
     {
         get
         {
-            //Just for the sake of performance, the verification will be kept in debug mode.
-            //This does imply that the C API will be shipped with a debug version...
+#if CHECKED_HANDLES
+            Validate(handle);
+#else
+            //Just for the sake of performance, the verification will be kept in debug mode unless the library is built with CheckedHandles=true.
             Debug.Assert(handle.TypeIndex == typeIndex, "Handle type must match the type of this instance directory. Did a bad handle get passed in?");
             Debug.Assert(handle.Index >= 0 && handle.Index < instances.Length, "Handle index must point to a slot within the directory. Did a bad handle get passed in?");
             Debug.Assert(handle.Version == instances[handle.Index].Version, "Handle version must match directory contained version. Did a handle get used after being removed?");
             Debug.Assert(instances[handle.Index].Instance != null, "There must be an instance associated with a handle.");
+#endif
             return instances[handle.Index].Instance;
         }
     }
@@ -72,9 +75,30 @@ public class InstanceDirectory<T> where T : class, IDisposable
             }
             ref var slot = ref instances[index];
             slot.Instance = instance;
-            return new InstanceHandle(index, ++slot.Version, typeIndex);
+            //Handles only have room for 4 bits of version.
+            slot.Version = (slot.Version + 1) & 0xF;
+            return new InstanceHandle(index, slot.Version, typeIndex);
         }
     }
+    /// <summary>
+    /// Checks that a handle refers to a live instance in this directory.
+    /// </summary>
+    /// <param name="handle">Handle to check.</param>
+    /// <exception cref="InvalidHandleException">The handle is of another type, points outside of the directory, is out of date, or points to an empty slot.</exception>
+    public void Validate(InstanceHandle handle)
+    {
+        if (handle.TypeIndex != typeIndex)
+            throw new InvalidHandleException("Handle does not match the type of this instance directory.");
+        //Grab the array once; a concurrent add may swap in a larger one.
+        var instances = this.instances;
+        if (handle.Index < 0 || handle.Index >= instances.Length)
+            throw new InvalidHandleException("Handle points to an index outside of the instance directory.");
+        if (handle.Version != instances[handle.Index].Version)
+            throw new InvalidHandleException("Handle is out of date. Is a handle being used after being removed?");
+        if (instances[handle.Index].Instance == null)
+            throw new InvalidHandleException("There is no instance associated with this handle.");
+    }
+
     /// <summary>
     /// Removes the instance associated with the given handle.
     /// </summary>
@@ -83,14 +107,7 @@ public class InstanceDirectory<T> where T : class, IDisposable
     {
         lock (locker)
         {
-            if (handle.TypeIndex != typeIndex)
-                throw new ArgumentException("Handle does not match the type of this instance directory.");
-            if (handle.Index < 0 || handle.Index > instances.Length)
-                throw new ArgumentOutOfRangeException("Handle points to an index outside of the instance directory.");
-            if (handle.Version != instances[handle.Index].Version)
-                throw new ArgumentException("Handle is out of date. Is a handle being used after being removed?");
-            if (instances[handle.Index].Instance == null)
-                throw new ArgumentException("There is no instance associated with this handle.");
+            Validate(handle);
             instances[handle.Index].Instance = null!;
             pool.Return(handle.Index);
         }
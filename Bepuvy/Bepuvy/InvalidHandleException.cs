@@ -0,0 +1,11 @@
+namespace Bepuvy;
+
+/// <summary>
+/// Thrown by a failed handle check. Recorded errors of this type are reported to the native side as invalid handles.
+/// </summary>
+public sealed class InvalidHandleException : ArgumentException
+{
+    public InvalidHandleException(string message) : base(message)
+    {
+    }
+}
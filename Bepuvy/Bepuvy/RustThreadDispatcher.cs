@@ -0,0 +1,112 @@
+using System.Runtime.ExceptionServices;
+using System.Runtime.InteropServices;
+using BepuUtilities;
+using BepuUtilities.Memory;
+
+namespace Bepuvy;
+
+/// <summary>
+/// Thread dispatcher that hands worker dispatches to a job system on the native side instead of owning its own threads.
+/// </summary>
+public unsafe class RustThreadDispatcher : IThreadDispatcher, IDisposable
+{
+    readonly void* context;
+    readonly delegate* unmanaged<void*, int, delegate* unmanaged<void*, int, void>, void*, void> dispatchFunction;
+    //The native side only sees an opaque pointer to this dispatcher, so keep it reachable and findable through a handle.
+    GCHandle selfHandle;
+
+    delegate*<int, IThreadDispatcher, void> workerBody;
+    Action<int> managedWorkerBody;
+    void* unmanagedContext;
+    object managedContext;
+    //Exceptions can't unwind through the native job system, so the first one thrown by a worker is rethrown on the dispatching thread.
+    Exception? workerException;
+
+    public int ThreadCount { get; }
+    public WorkerBufferPools WorkerPools { get; }
+    public void* UnmanagedContext => unmanagedContext;
+    public object ManagedContext => managedContext;
+
+    /// <summary>
+    /// Creates a dispatcher that forwards to a native job system.
+    /// </summary>
+    /// <param name="threadCount">Maximum number of workers the native job system can run concurrently.</param>
+    /// <param name="threadPoolAllocationBlockSize">Minimum size in bytes of blocks allocated in per-worker buffer pools.</param>
+    /// <param name="context">Opaque pointer passed back to the dispatch function.</param>
+    /// <param name="dispatchFunction">Runs the given worker body once for every worker index below the worker count, concurrently, and returns once all have completed.</param>
+    public RustThreadDispatcher(int threadCount, int threadPoolAllocationBlockSize, void* context, delegate* unmanaged<void*, int, delegate* unmanaged<void*, int, void>, void*, void> dispatchFunction)
+    {
+        ThreadCount = threadCount;
+        WorkerPools = new WorkerBufferPools(threadCount, threadPoolAllocationBlockSize);
+        this.context = context;
+        this.dispatchFunction = dispatchFunction;
+        selfHandle = GCHandle.Alloc(this);
+    }
+
+    [UnmanagedCallersOnly]
+    static void ExecuteWorker(void* dispatcher, int workerIndex)
+    {
+        var self = (RustThreadDispatcher)GCHandle.FromIntPtr((IntPtr)dispatcher).Target!;
+        try
+        {
+            if (self.workerBody != null)
+                self.workerBody(workerIndex, self);
+            else
+                self.managedWorkerBody(workerIndex);
+        }
+        catch (Exception exception)
+        {
+            Interlocked.CompareExchange(ref self.workerException, exception, null);
+        }
+    }
+
+    void Dispatch(int maximumWorkerCount)
+    {
+        var workerCount = Math.Min(ThreadCount, maximumWorkerCount);
+        if (workerCount > 0)
+            dispatchFunction(context, workerCount, &ExecuteWorker, (void*)GCHandle.ToIntPtr(selfHandle));
+        var exception = Interlocked.Exchange(ref workerException, null);
+        if (exception != null)
+            ExceptionDispatchInfo.Throw(exception);
+    }
+
+    public void DispatchWorkers(delegate*<int, IThreadDispatcher, void> workerBody, int maximumWorkerCount = int.MaxValue, void* unmanagedContext = null, object managedContext = null)
+    {
+        this.workerBody = workerBody;
+        this.unmanagedContext = unmanagedContext;
+        this.managedContext = managedContext;
+        try
+        {
+            Dispatch(maximumWorkerCount);
+        }
+        finally
+        {
+            this.workerBody = null;
+            this.unmanagedContext = null;
+            this.managedContext = null;
+        }
+    }
+
+    public void DispatchWorkers(Action<int> workerBody, int maximumWorkerCount = int.MaxValue, void* unmanagedContext = null, object managedContext = null)
+    {
+        managedWorkerBody = workerBody;
+        this.unmanagedContext = unmanagedContext;
+        this.managedContext = managedContext;
+        try
+        {
+            Dispatch(maximumWorkerCount);
+        }
+        finally
+        {
+            managedWorkerBody = null;
+            this.unmanagedContext = null;
+            this.managedContext = null;
+        }
+    }
+
+    public void Dispose()
+    {
+        WorkerPools.Dispose();
+        selfHandle.Free();
+    }
+}
@@ -1,9 +1,16 @@
 use std::{
+    collections::hash_map::DefaultHasher,
     env,
-    path::PathBuf,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
     process::{Command, Output},
 };
 
+#[path = "build/target.rs"]
+mod target;
+
+use target::{simd_width, Target};
+
 fn check_command_output(output: &Output, context: &str) {
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -20,7 +27,7 @@ fn get_home_dir() -> PathBuf {
 }
 
 fn find_native_aot_version() -> String {
-    let csproj_content = std::fs::read_to_string("./Bepuvy/Bepuvy/Bepuvy.csproj")
+    let csproj_content = std::fs::read_to_string(Path::new(PROJECT_DIR).join("Bepuvy.csproj"))
         .expect("Failed to read .csproj file");
 
     for line in csproj_content.lines() {
@@ -90,82 +97,415 @@ fn install_dotnet() -> PathBuf {
     dotnet_path
 }
 
-fn register_change_detection() {
-    let cs_dir = std::fs::read_dir("./Bepuvy/Bepuvy").expect("Unable to read cs directory");
-
-    for entry in cs_dir.filter_map(|e| e.ok()) {
-        if let Ok(ft) = entry.file_type() {
-            if ft.is_file()
-                && entry
-                    .file_name()
-                    .to_str()
-                    .map(|s| s.ends_with(".cs"))
-                    .unwrap_or(false)
-            {
-                println!(
-                    "cargo:rerun-if-changed=Bepuvy/Bepuvy/{}",
-                    entry.file_name().to_str().unwrap()
-                );
+/// Directory of the C# project the native library is published from.
+const PROJECT_DIR: &str = "Bepuvy/Bepuvy";
+
+/// Collects every `.cs` and `.csproj` file under `dir`, along with the subdirectories holding them, skipping build output.
+fn collect_project_inputs(dir: &Path, inputs: &mut Vec<PathBuf>, subdirs: &mut Vec<PathBuf>) {
+    let entries = std::fs::read_dir(dir)
+        .unwrap_or_else(|error| panic!("Unable to read {}: {error}", dir.display()));
+
+    for entry in entries.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        let Ok(ft) = entry.file_type() else {
+            continue;
+        };
+        if ft.is_dir() {
+            // Output of earlier builds that published into the source tree.
+            if !matches!(entry.file_name().to_str(), Some("bin" | "obj" | "dist")) {
+                collect_project_inputs(&path, inputs, subdirs);
+                subdirs.push(path);
             }
+        } else if ft.is_file()
+            && path
+                .extension()
+                .is_some_and(|ext| ext == "cs" || ext == "csproj")
+        {
+            inputs.push(path);
         }
     }
 }
 
-fn main() {
-    println!("cargo:warning=Build script starting");
+/// Asks cargo to rerun the build script when any project input changes, returning the inputs in a stable order.
+fn register_change_detection() -> Vec<PathBuf> {
+    let mut inputs = Vec::new();
+    let mut subdirs = Vec::new();
+    collect_project_inputs(Path::new(PROJECT_DIR), &mut inputs, &mut subdirs);
+    inputs.sort();
+
+    // Cargo scans directories recursively, which also catches files added to them.
+    // The project directory itself is left out, since .NET writes its build output there.
+    for subdir in &subdirs {
+        println!("cargo:rerun-if-changed={}", subdir.display());
+    }
+    for input in &inputs {
+        println!("cargo:rerun-if-changed={}", input.display());
+    }
+
+    inputs
+}
+
+/// Hashes the publish arguments and the path and contents of every input, so a change to any of them republishes.
+fn hash_inputs(inputs: &[PathBuf], publish_args: &[String]) -> String {
+    let mut hasher = DefaultHasher::new();
+    publish_args.hash(&mut hasher);
+    for input in inputs {
+        input.hash(&mut hasher);
+        std::fs::read(input)
+            .unwrap_or_else(|error| panic!("Unable to read {}: {error}", input.display()))
+            .hash(&mut hasher);
+    }
+    format!("{:016x}", hasher.finish())
+}
 
-    // Get target architecture
-    let arch = match (env::consts::OS, env::consts::ARCH) {
-        ("macos", "aarch64") => "osx-arm64",
-        ("windows", "x86_64") => "win-x64",
-        ("linux", "x86_64") => "linux-x64",
-        (os, arch) => panic!("{} on {} is not supported by Bepuvy.", os, arch),
+/// An archive of the NativeAOT runtime, found under the ILCompiler package's `sdk` or `framework` directory.
+struct RuntimeLibrary {
+    name: &'static str,
+    /// Prefix shared by the symbols Bepuvy imports from the archive, if Bepuvy calls into it directly.
+    symbol_prefix: Option<&'static str>,
+    /// Cargo feature that links the archive, or `None` if it is always linked.
+    feature: Option<&'static str>,
+    /// Runtime identifier the archive is limited to, if it only exists for one.
+    rid: Option<&'static str>,
+}
+
+const RUNTIME_LIBRARIES: [RuntimeLibrary; 8] = [
+    // "bootstrapperdll",
+    //"Runtime.VxsortEnabled",
+    RuntimeLibrary {
+        name: "Runtime.WorkstationGC",
+        symbol_prefix: None,
+        feature: None,
+        rid: None,
+    },
+    RuntimeLibrary {
+        name: "eventpipe-disabled",
+        symbol_prefix: None,
+        feature: None,
+        rid: None,
+    },
+    RuntimeLibrary {
+        name: "System.Native",
+        symbol_prefix: Some("SystemNative_"),
+        feature: None,
+        rid: None,
+    },
+    RuntimeLibrary {
+        name: "System.Globalization.Native",
+        symbol_prefix: Some("GlobalizationNative_"),
+        feature: None,
+        rid: None,
+    },
+    RuntimeLibrary {
+        name: "System.IO.Compression.Native",
+        symbol_prefix: Some("CompressionNative_"),
+        feature: Some("runtime-compression"),
+        rid: None,
+    },
+    RuntimeLibrary {
+        name: "System.Net.Security.Native",
+        symbol_prefix: Some("NetSecurityNative_"),
+        feature: Some("runtime-crypto"),
+        rid: None,
+    },
+    RuntimeLibrary {
+        name: "System.Security.Cryptography.Native.OpenSsl",
+        symbol_prefix: Some("CryptoNative_"),
+        feature: Some("runtime-crypto"),
+        rid: None,
+    },
+    RuntimeLibrary {
+        name: "System.Security.Cryptography.Native.Apple",
+        symbol_prefix: Some("AppleCryptoNative_"),
+        feature: Some("runtime-crypto"),
+        rid: Some("osx-arm64"),
+    },
+];
+
+fn is_feature_enabled(feature: &str) -> bool {
+    let var = format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"));
+    env::var_os(var).is_some()
+}
+
+/// Archives of the NativeAOT runtime that are linked alongside Bepuvy.
+///
+/// Only the archives the shim needs are linked by default; the rest pull in system dependencies like OpenSSL,
+/// so they are behind the `runtime-*` features.
+fn runtime_libraries(arch: &str) -> Vec<&'static str> {
+    RUNTIME_LIBRARIES
+        .iter()
+        .filter(|lib| lib.rid.is_none_or(|rid| rid == arch))
+        .filter(|lib| lib.feature.is_none_or(is_feature_enabled))
+        .map(|lib| lib.name)
+        .collect()
+}
+
+/// Lists the symbols a static library imports, or `None` if `nm` isn't installed to read them.
+fn undefined_symbols(library: &Path) -> Option<Vec<String>> {
+    let output = match Command::new("nm").arg("-u").arg(library).output() {
+        Ok(output) => output,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(error) => panic!("Failed to run nm on {}: {error}", library.display()),
     };
+    check_command_output(
+        &output,
+        &format!("listing the symbols of {}", library.display()),
+    );
 
-    register_change_detection();
+    let symbols = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        // Archive members are listed as `member.o:` headers.
+        .filter(|line| !line.is_empty() && !line.ends_with(':'))
+        .filter_map(|line| line.split_whitespace().last())
+        // Mach-O prefixes every C symbol with an underscore.
+        .map(|symbol| symbol.strip_prefix('_').unwrap_or(symbol).to_string())
+        .collect();
+    Some(symbols)
+}
 
-    // Find .NET and set up environment
-    let out_dir_dotnet =
-        PathBuf::from(env::var("OUT_DIR").expect("Failed to get OUT_DIR")).join("dotnet/dotnet");
+/// Makes sure every runtime symbol Bepuvy imports comes from an archive that will be linked,
+/// so a trimmed-out dependency fails here, naming the feature to enable, instead of as an undefined reference in the linker.
+///
+/// Reading the imports needs `nm`. Without it the build fails, unless `BEPUVY_SKIP_SYMBOL_CHECK` is set to skip the check.
+fn check_runtime_symbols(library: &Path, target: &Target) {
+    println!("cargo:rerun-if-env-changed=BEPUVY_SKIP_SYMBOL_CHECK");
+    if env::var_os("BEPUVY_SKIP_SYMBOL_CHECK").is_some() {
+        println!("cargo:warning=BEPUVY_SKIP_SYMBOL_CHECK is set; skipping the check that the linked runtime libraries cover Bepuvy's imports");
+        return;
+    }
+    let Some(symbols) = undefined_symbols(library) else {
+        panic!(
+            "nm was not found, so the runtime libraries Bepuvy imports from can't be checked.\n\
+             Install binutils (or the Xcode command line tools on macOS) to provide nm, \
+             or set BEPUVY_SKIP_SYMBOL_CHECK=1 to link without the check."
+        );
+    };
 
-    let dotnet_path = if which::which("dotnet").is_ok() {
-        PathBuf::from("dotnet")
-    } else if out_dir_dotnet.exists() {
-        out_dir_dotnet
+    let linked = runtime_libraries(target.rid);
+    let mut missing: Vec<String> = Vec::new();
+    for lib in RUNTIME_LIBRARIES
+        .iter()
+        .filter(|lib| !linked.contains(&lib.name))
+    {
+        let Some(prefix) = lib.symbol_prefix else {
+            continue;
+        };
+        let imported: Vec<&str> = symbols
+            .iter()
+            .filter(|symbol| symbol.starts_with(prefix))
+            .map(String::as_str)
+            .collect();
+        if imported.is_empty() {
+            continue;
+        }
+        let fix = match lib.feature {
+            Some(feature) => format!("enable the `{feature}` feature"),
+            None => format!("{} is not available for {}", lib.name, target.rid),
+        };
+        missing.push(format!(
+            "{} imports {} from {}; {fix}",
+            library.display(),
+            imported.join(", "),
+            lib.name
+        ));
+    }
+
+    if !missing.is_empty() {
+        panic!(
+            "Bepuvy needs runtime libraries that are not linked:\n{}\n\
+             The C# shim likely started using an API the csproj doesn't trim away.",
+            missing.join("\n")
+        );
+    }
+}
+
+/// Runtime objects linked by their exact file name.
+const VERBATIM_RUNTIME_LIBRARIES: [&str; 2] = ["libbootstrapperdll.o", "libstandalonegc-enabled.a"];
+
+fn static_library_file_name(target: &Target, name: &str) -> String {
+    if target.is_windows() {
+        format!("{name}.lib")
     } else {
-        install_dotnet()
-    };
+        format!("lib{name}.a")
+    }
+}
+
+/// Whether Bepuvy is built as a shared library and loaded at runtime, rather than linked statically with the NativeAOT runtime.
+fn is_dynamic() -> bool {
+    env::var_os("CARGO_FEATURE_DYNAMIC").is_some()
+}
+
+fn dynamic_library_file_name(target: &Target, name: &str) -> String {
+    match target.os.as_str() {
+        "windows" => format!("{name}.dll"),
+        "macos" => format!("lib{name}.dylib"),
+        _ => format!("lib{name}.so"),
+    }
+}
+
+/// Gets the file name the Bepuvy library is linked or loaded under.
+fn bepuvy_library_file_name(target: &Target) -> String {
+    if is_dynamic() {
+        dynamic_library_file_name(target, "Bepuvy")
+    } else {
+        static_library_file_name(target, "Bepuvy")
+    }
+}
+
+/// Gets the directory holding a prebuilt Bepuvy library and the NativeAOT runtime archives, if the build should link those
+/// instead of publishing the C# project.
+fn prebuilt_dir(target: &Target) -> Option<PathBuf> {
+    println!("cargo:rerun-if-env-changed=BEPUVY_PREBUILT_DIR");
+    let dir = env::var_os("BEPUVY_PREBUILT_DIR").map(PathBuf::from);
+    if dir.is_none() && env::var_os("CARGO_FEATURE_PREBUILT").is_some() {
+        panic!(
+            "The `prebuilt` feature is enabled, but BEPUVY_PREBUILT_DIR is not set.\n\
+             Point it at a directory containing {}{}; nothing will be downloaded.",
+            bepuvy_library_file_name(target),
+            if is_dynamic() {
+                ""
+            } else {
+                " and the NativeAOT runtime archives"
+            }
+        );
+    }
+    dir
+}
 
-    // Build the project
-    let output = Command::new(&dotnet_path)
-        .arg("publish")
-        .arg("/p:NativeLib=Static")
-        .arg("-p:EnableNativeEventPipe=false")
-        .arg(if arch == "osx-arm64" {
-            "-p:IlcInstructionSet=apple-m1"
+/// Makes sure a prebuilt directory holds everything that will be linked, so a missing file fails here instead of in the linker.
+fn check_prebuilt_dir(dir: &Path, target: &Target) {
+    let arch = target.rid;
+    let mut required = vec![bepuvy_library_file_name(target)];
+    // A shared library carries the runtime within itself.
+    if !is_dynamic() {
+        required.extend(
+            runtime_libraries(arch)
+                .into_iter()
+                .map(|name| static_library_file_name(target, name)),
+        );
+        required.extend(
+            VERBATIM_RUNTIME_LIBRARIES
+                .iter()
+                .map(|name| name.to_string()),
+        );
+    }
+
+    let missing: Vec<String> = required
+        .into_iter()
+        .filter(|name| !dir.join(name).is_file())
+        .collect();
+    if !missing.is_empty() {
+        let instructions = if is_dynamic() {
+            format!(
+                "Publish Bepuvy with `dotnet publish /p:NativeLib=Shared -r {arch}` and copy the library there as {}.",
+                bepuvy_library_file_name(target)
+            )
         } else {
-            "-p:IlcInstructionSet=x86-x64-v3"
-        })
-        .arg("-p:InvariantGlobalization=true")
-        .arg("--use-current-runtime")
-        .current_dir("./Bepuvy/Bepuvy")
-        .arg("-o")
-        .arg("dist")
-        .arg("-r")
-        .arg(arch)
-        .output()
-        .expect("Failed to execute dotnet command");
-
-    check_command_output(&output, "building Bepuvy");
-
-    // Handle library extension and renaming
-    let ext = if cfg!(windows) { ".lib" } else { ".a" };
-    let old_name = format!("./Bepuvy/Bepuvy/dist/Bepuvy{}", ext);
-    let new_name = format!("./Bepuvy/Bepuvy/dist/libBepuvy{}", ext);
-
-    std::fs::rename(&old_name, &new_name).expect("Failed to rename library file");
-
-    // Find NativeAOT version and set up linking
+            format!(
+                "Publish Bepuvy with `dotnet publish /p:NativeLib=Static -r {arch}`, rename the library to {}, and copy it \
+                 along with the contents of the runtime.{arch}.microsoft.dotnet.ilcompiler package's sdk and framework directories.",
+                bepuvy_library_file_name(target)
+            )
+        };
+        panic!(
+            "BEPUVY_PREBUILT_DIR ({}) is missing: {}.\n{instructions}",
+            dir.display(),
+            missing.join(", ")
+        );
+    }
+
+    if env::var_os("CARGO_FEATURE_CHECKED_HANDLES").is_some() {
+        println!("cargo:warning=checked-handles needs a prebuilt library published with -p:CheckedHandles=true; simulations will refuse to start otherwise");
+    }
+}
+
+/// Publishes the C# project into `OUT_DIR`, returning the directory holding the library.
+///
+/// The library is static unless the `dynamic` feature is enabled, in which case it is shared and carries the runtime itself.
+/// Publishing is skipped when the inputs and arguments hash the same as the last published library.
+fn publish_bepuvy(target: &Target, instruction_set: Option<&str>) -> PathBuf {
+    let arch = target.rid;
+    let inputs = register_change_detection();
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("Failed to get OUT_DIR"));
+    let publish_dir = out_dir.join("bepuvy");
+    let library = publish_dir.join(bepuvy_library_file_name(target));
+    let hash_file = publish_dir.join("inputs.hash");
+
+    let mut publish_args: Vec<String> = [
+        "publish",
+        if is_dynamic() {
+            "/p:NativeLib=Shared"
+        } else {
+            "/p:NativeLib=Static"
+        },
+        "-p:EnableNativeEventPipe=false",
+        "-r",
+        arch,
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect();
+    if let Some(instruction_set) = instruction_set {
+        publish_args.push(format!("-p:IlcInstructionSet={instruction_set}"));
+    }
+    if env::var_os("CARGO_FEATURE_CHECKED_HANDLES").is_some() {
+        publish_args.push("-p:CheckedHandles=true".to_string());
+    }
+
+    let hash = hash_inputs(&inputs, &publish_args);
+    let up_to_date = library.is_file()
+        && std::fs::read_to_string(&hash_file).is_ok_and(|published| published == hash);
+
+    if !up_to_date {
+        // Find .NET and set up environment
+        let out_dir_dotnet = out_dir.join("dotnet/dotnet");
+
+        let dotnet_path = if which::which("dotnet").is_ok() {
+            PathBuf::from("dotnet")
+        } else if out_dir_dotnet.exists() {
+            out_dir_dotnet
+        } else {
+            install_dotnet()
+        };
+
+        // Build the project; intermediate files go to OUT_DIR as well so the source tree stays clean.
+        let output = Command::new(&dotnet_path)
+            .args(&publish_args)
+            .current_dir(PROJECT_DIR)
+            .arg("--artifacts-path")
+            .arg(out_dir.join("bepuvy-artifacts"))
+            .arg("-o")
+            .arg(&publish_dir)
+            .output()
+            .expect("Failed to execute dotnet command");
+
+        check_command_output(&output, "building Bepuvy");
+
+        // The library is linked or loaded under its platform's naming convention.
+        // Copy rather than rename, so a library left by an earlier build is simply replaced.
+        let ext = match (is_dynamic(), target.os.as_str()) {
+            (false, "windows") => ".lib",
+            (false, _) => ".a",
+            (true, "windows") => ".dll",
+            (true, "macos") => ".dylib",
+            (true, _) => ".so",
+        };
+        let published = publish_dir.join(format!("Bepuvy{ext}"));
+        if published != library {
+            std::fs::copy(&published, &library)
+                .unwrap_or_else(|error| panic!("Failed to copy {}: {error}", published.display()));
+        }
+
+        std::fs::write(&hash_file, &hash).expect("Failed to record the published input hash");
+    }
+
+    publish_dir
+}
+
+/// Gets the directories of the restored ILCompiler package holding the NativeAOT runtime archives.
+fn native_aot_runtime_dirs(target: &Target) -> Vec<PathBuf> {
+    let arch = target.rid;
     let nativeaot_version = find_native_aot_version();
     let home_dir = get_home_dir();
 
@@ -182,44 +522,69 @@ fn main() {
         );
     }
 
-    println!("cargo:rustc-link-search={}/sdk", aot_base.display());
-    println!("cargo:rustc-link-search={}/framework", aot_base.display());
+    vec![aot_base.join("sdk"), aot_base.join("framework")]
+}
+
+fn main() {
+    println!("cargo:warning=Build script starting");
+
+    let target = Target::from_env();
+    let instruction_set = target.instruction_set();
 
-    let mut libs = vec![
-        // "bootstrapperdll",
-        //"Runtime.VxsortEnabled",
-        "Runtime.WorkstationGC",
-        "eventpipe-disabled",
-        "System.Native",
-        "System.IO.Compression.Native",
-        "System.Globalization.Native",
-        "System.Net.Security.Native",
-        "System.Security.Cryptography.Native.OpenSsl",
-    ];
+    // The wide types passed to callbacks have to match the lane count of the native library.
+    println!("cargo:rustc-check-cfg=cfg(bepuvy_simd_width, values(\"4\", \"8\"))");
+    println!(
+        "cargo:rustc-cfg=bepuvy_simd_width=\"{}\"",
+        simd_width(instruction_set.as_deref())
+    );
 
-    if arch == "osx-arm64" {
-        libs.push("System.Security.Cryptography.Native.Apple");
+    // A prebuilt directory skips the .NET toolchain entirely.
+    let prebuilt = prebuilt_dir(&target);
+    let library_dir = match &prebuilt {
+        Some(dir) => {
+            check_prebuilt_dir(dir, &target);
+            dir.clone()
+        }
+        None => publish_bepuvy(&target, instruction_set.as_deref()),
     };
 
-    for lib in libs {
-        println!("cargo:rustc-link-lib=static:-bundle,+whole-archive={lib}");
+    // The shared library is opened at runtime, so nothing gets linked.
+    if is_dynamic() {
+        println!(
+            "cargo:rustc-env=BEPUVY_LIBRARY_PATH={}",
+            library_dir
+                .join(bepuvy_library_file_name(&target))
+                .display()
+        );
+        return;
     }
 
-    println!("cargo:rustc-link-lib=static:-bundle,+whole-archive,+verbatim=libbootstrapperdll.o");
-    println!(
-        "cargo:rustc-link-lib=static:-bundle,+whole-archive,+verbatim=libstandalonegc-enabled.a"
+    check_runtime_symbols(
+        &library_dir.join(bepuvy_library_file_name(&target)),
+        &target,
     );
 
-    let dist_dir = env::current_dir()
-        .expect("Failed to get current directory")
-        .join("Bepuvy/Bepuvy/dist");
+    let mut search_dirs = vec![library_dir];
+    if prebuilt.is_none() {
+        search_dirs.extend(native_aot_runtime_dirs(&target));
+    }
+
+    for dir in &search_dirs {
+        println!("cargo:rustc-link-search={}", dir.display());
+    }
+
+    for lib in runtime_libraries(target.rid) {
+        println!("cargo:rustc-link-lib=static:-bundle,+whole-archive={lib}");
+    }
+
+    for lib in VERBATIM_RUNTIME_LIBRARIES {
+        println!("cargo:rustc-link-lib=static:-bundle,+whole-archive,+verbatim={lib}");
+    }
 
-    println!("cargo:rustc-link-search={}", dist_dir.display());
     println!("cargo:rustc-link-lib=static:-bundle,+whole-archive=Bepuvy");
     println!("cargo:rustc-link-lib=c++");
 
-    #[cfg(any(target_os = "linux"))]
-    {
+    if target.os == "linux" {
         println!("cargo:rustc-link-arg=-z");
         println!("cargo:rustc-link-arg=nostart-stop-gc");
     }
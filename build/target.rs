@@ -0,0 +1,120 @@
+//! Target detection for the build script.
+//!
+//! Kept free of external crates so the integration tests can include it and exercise it without running a build.
+
+use std::env;
+
+/// Instruction sets NativeAOT can compile for on x86-64, from most to least capable, with the Rust target features each one requires.
+const X64_INSTRUCTION_SETS: [(&str, &[&str]); 3] = [
+    (
+        "x86-x64-v4",
+        &[
+            "avx512f", "avx512bw", "avx512cd", "avx512dq", "avx512vl", "avx2", "bmi1", "bmi2",
+            "fma", "f16c", "lzcnt", "movbe",
+        ],
+    ),
+    (
+        "x86-x64-v3",
+        &[
+            "avx", "avx2", "bmi1", "bmi2", "fma", "f16c", "lzcnt", "movbe", "sse4.2", "popcnt",
+        ],
+    ),
+    (
+        "x86-x64-v2",
+        &["cmpxchg16b", "popcnt", "sse3", "sse4.1", "sse4.2", "ssse3"],
+    ),
+];
+
+/// The platform being compiled for.
+///
+/// Read from cargo's `CARGO_CFG_TARGET_*` variables rather than `env::consts`, which describe the machine running the build script.
+pub struct Target {
+    pub os: String,
+    /// .NET runtime identifier of the target.
+    pub rid: &'static str,
+    pub features: Vec<String>,
+}
+
+impl Target {
+    pub fn from_env() -> Self {
+        let cfg = |name: &str| env::var(format!("CARGO_CFG_TARGET_{name}")).unwrap_or_default();
+        Self::new(&cfg("OS"), &cfg("ARCH"), &cfg("ENV"), &cfg("FEATURE"))
+    }
+
+    /// Describes a target from the values of cargo's `target_os`, `target_arch`, `target_env` and comma separated `target_feature` cfgs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if NativeAOT has no runtime for the target.
+    pub fn new(os: &str, arch: &str, target_env: &str, features: &str) -> Self {
+        let rid = match (os, arch, target_env) {
+            ("macos", "aarch64", _) => "osx-arm64",
+            ("windows", "x86_64", _) => "win-x64",
+            ("linux", "x86_64", "musl") => "linux-musl-x64",
+            ("linux", "x86_64", _) => "linux-x64",
+            ("linux", "aarch64", "gnu") => "linux-arm64",
+            (os, arch, target_env) => panic!(
+                "{arch}-{os}-{target_env} is not supported by Bepuvy. \
+                 Supported targets are osx-arm64, win-x64, linux-x64, linux-musl-x64 and linux-arm64."
+            ),
+        };
+
+        let features = features
+            .split(',')
+            .filter(|feature| !feature.is_empty())
+            .map(String::from)
+            .collect();
+
+        Target {
+            os: os.to_string(),
+            rid,
+            features,
+        }
+    }
+
+    pub fn is_windows(&self) -> bool {
+        self.os == "windows"
+    }
+
+    /// Gets the instruction set to pass to the IL compiler, or `None` to let it target the architecture's baseline.
+    ///
+    /// `BEPUVY_ILC_INSTRUCTION_SET` overrides the choice; see [`Target::select_instruction_set`].
+    pub fn instruction_set(&self) -> Option<String> {
+        println!("cargo:rerun-if-env-changed=BEPUVY_ILC_INSTRUCTION_SET");
+        self.select_instruction_set(env::var("BEPUVY_ILC_INSTRUCTION_SET").ok().as_deref())
+    }
+
+    /// Picks the instruction set to pass to the IL compiler, or `None` to let it target the architecture's baseline.
+    ///
+    /// A set override is used as is, with an empty one meaning the baseline. Otherwise it follows the target features
+    /// rustc compiles for, so `-C target-cpu` applies to the physics code as well and the default build runs on any CPU
+    /// of the architecture.
+    pub fn select_instruction_set(&self, override_set: Option<&str>) -> Option<String> {
+        if let Some(instruction_set) = override_set {
+            return Some(instruction_set.to_string()).filter(|set| !set.is_empty());
+        }
+
+        match self.rid {
+            "osx-arm64" => Some("apple-m1".to_string()),
+            "win-x64" | "linux-x64" | "linux-musl-x64" => X64_INSTRUCTION_SETS
+                .iter()
+                .find(|(_, required)| {
+                    required
+                        .iter()
+                        .all(|feature| self.features.iter().any(|enabled| enabled == feature))
+                })
+                .map(|(instruction_set, _)| instruction_set.to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// Gets the number of `f32` lanes in .NET's `Vector<T>` for code compiled with an instruction set.
+///
+/// `Vector<T>` is 256 bits wide once AVX2 is available, even with AVX-512, and 128 bits otherwise.
+pub fn simd_width(instruction_set: Option<&str>) -> usize {
+    match instruction_set {
+        Some(set) if set.contains("avx2") || set.contains("-v3") || set.contains("-v4") => 8,
+        _ => 4,
+    }
+}
@@ -1,7 +1,54 @@
-use bepuvy_sys::bepu::{buffer_pool::BufferPool, simulation::Simulation};
+use bepuvy_sys::{
+    bepu::simulation::Simulation,
+    types::{
+        body::{BodyActivityDescription, BodyDescription, BodyInertia, CollidableDescription},
+        ccd::ContinuousDetection,
+        math::scalar::{RigidPose, Symmetric3x3, Vector3},
+        shapes::{Box, Sphere},
+        statics::StaticDescription,
+    },
+};
 
 fn main() {
-    let pool = BufferPool::default();
+    let mut sim = Simulation::builder()
+        .gravity(Vector3::new(0.0, -10.0, 0.0))
+        .substeps(4)
+        .velocity_iterations(1)
+        .capacity(64, 16, 64)
+        .build()
+        .expect("default settings are valid");
 
-    let sim = Simulation::new(&pool);
+    let ground = sim.shapes_mut().add(Box::new(100.0, 1.0, 100.0));
+    sim.add_static(StaticDescription::create_discrete(
+        RigidPose::from_position(Vector3::new(0.0, -0.5, 0.0)),
+        ground.index(),
+    ))
+    .expect("failed to add the ground");
+
+    let radius = 0.5;
+    let mass = 1.0;
+    let ball = sim.shapes_mut().add(Sphere { radius });
+    // Solid sphere inertia is 2/5 * m * r^2 about every axis.
+    let inverse_inertia = 1.0 / (0.4 * mass * radius * radius);
+    let description = BodyDescription::create_dynamic_at_rest(
+        RigidPose::from_position(Vector3::new(0.0, 5.0, 0.0)),
+        BodyInertia {
+            inverse_inertia_tensor: Symmetric3x3 {
+                xx: inverse_inertia,
+                yy: inverse_inertia,
+                zz: inverse_inertia,
+                ..Symmetric3x3::zero()
+            },
+            inverse_mass: 1.0 / mass,
+            pad: 0,
+        },
+        CollidableDescription::new(ball.index(), 0.0, f32::MAX, ContinuousDetection::discrete()),
+        BodyActivityDescription::new(0.01, 32),
+    );
+    let body = sim.add_body(description).expect("failed to add the ball");
+
+    for _ in 0..120 {
+        sim.step(1.0 / 60.0, None).expect("step failed");
+    }
+    println!("ball came to rest at {:?}", sim.body(body).pose().position);
 }
@@ -1,7 +1,27 @@
-use crate::types::handles::BufferPoolHandle;
+use std::{
+    ops::{Deref, DerefMut},
+    sync::{Mutex, MutexGuard, PoisonError},
+};
 
+use crate::{
+    types::{
+        handles::BufferPoolHandle,
+        utilities::{Buffer, ByteBuffer},
+    },
+    Error,
+};
+
+use super::memory::MemoryReport;
+
+/// A pool of native memory that simulations and shapes allocate from.
+///
+/// The pool can be shared between threads, for example through an `Arc`. Bepu's buffer pool isn't thread safe,
+/// so every allocation, deallocation and simulation call that may allocate takes the pool's lock first.
+/// Simulation callbacks run while the simulation holds the lock, so allocating from the same pool inside one deadlocks.
 pub struct BufferPool {
     handle: BufferPoolHandle,
+    /// Serializes access to the native pool. Guards no Rust data, so a poisoned lock is still usable.
+    lock: Mutex<()>,
 }
 
 impl Drop for BufferPool {
@@ -10,8 +30,9 @@ impl Drop for BufferPool {
         //
         // The only place to obtain a buffer pool handle is the constructor of this type.
         // That constructor ties the handle's lifetime to 'self' so when 'self' is dropped there are no references to handle.
-        unsafe {
-            crate::ffi::buffer_pool::destroy_buffer_pool(self.handle);
+        let destroyed = unsafe { crate::ffi::buffer_pool::destroy_buffer_pool(self.handle) };
+        if !destroyed && !std::thread::panicking() {
+            Error::panic_native("destroying a buffer pool");
         }
     }
 }
@@ -23,23 +44,269 @@ impl Default for BufferPool {
 }
 
 impl BufferPool {
+    /// Creates a buffer pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if Bepu rejects the settings; see [`BufferPool::try_new`].
     pub fn new(minimum_block_allocation_size: i32, expected_used_slot_count_per_pool: i32) -> Self {
-        BufferPool {
-            // SAFETY:
-            //
-            // Creating a handle is always safe as long as you use it responsibly.
-            // `crate::ffi::buffer_pool::destroy_buffer_pool` needs to be called for every handle created.
-            // This is handled in the 'Drop' impl for `BufferPool`
-            handle: unsafe {
-                crate::ffi::buffer_pool::create_buffer_pool(
-                    minimum_block_allocation_size,
-                    expected_used_slot_count_per_pool,
-                )
-            },
+        Self::try_new(
+            minimum_block_allocation_size,
+            expected_used_slot_count_per_pool,
+        )
+        .unwrap_or_else(|error| panic!("failed to create buffer pool: {error}"))
+    }
+
+    /// Creates a buffer pool, returning an error if Bepu rejects the settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `minimum_block_allocation_size`: Minimum size of individual block allocations. Must be a power of 2.
+    /// * `expected_used_slot_count_per_pool`: Number of suballocations to preallocate reference space for.
+    pub fn try_new(
+        minimum_block_allocation_size: i32,
+        expected_used_slot_count_per_pool: i32,
+    ) -> Result<Self, Error> {
+        // SAFETY:
+        //
+        // Creating a handle is always safe as long as you use it responsibly.
+        // `crate::ffi::buffer_pool::destroy_buffer_pool` needs to be called for every handle created.
+        // This is handled in the 'Drop' impl for `BufferPool`
+        let handle = unsafe {
+            crate::ffi::buffer_pool::create_buffer_pool(
+                minimum_block_allocation_size,
+                expected_used_slot_count_per_pool,
+            )
+        };
+        if handle.is_null() {
+            return Err(Error::take_native());
         }
+        Ok(BufferPool {
+            handle,
+            lock: Mutex::new(()),
+        })
+    }
+
+    /// Gets the number of bytes allocated from native memory in this pool,
+    /// including memory that isn't currently handed out.
+    pub fn allocated_memory(&self) -> u64 {
+        let _pool = self.lock();
+        let mut size = 0;
+        // SAFETY:
+        //
+        // The handle is owned by `self` and is valid for as long as `self` is alive.
+        let measured = unsafe {
+            crate::ffi::buffer_pool::get_allocated_memory_size_in_pool(self.handle, &mut size)
+        };
+        if !measured {
+            Error::panic_native("measuring a buffer pool");
+        }
+        size
+    }
+
+    /// Gets a report of the native memory held by this pool.
+    pub fn memory_report(&self) -> MemoryReport {
+        MemoryReport {
+            native_bytes: self.allocated_memory(),
+            ..Default::default()
+        }
+    }
+
+    /// Allocates a buffer of `len` default-initialized elements from the pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer would be larger than `i32::MAX` bytes.
+    pub fn alloc<T: Copy + Default>(&self, len: usize) -> PooledBuffer<'_, T> {
+        let mut buffer = PooledBuffer::empty(self);
+        buffer.resize(len);
+        buffer
+    }
+
+    /// Allocates a buffer from the pool holding a copy of `values`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer would be larger than `i32::MAX` bytes.
+    pub fn alloc_from_slice<T: Copy>(&self, values: &[T]) -> PooledBuffer<'_, T> {
+        let mut buffer = PooledBuffer::empty(self);
+        // SAFETY:
+        //
+        // Every element of the new allocation is written below before the buffer is handed out.
+        unsafe {
+            buffer.reallocate(values.len());
+            std::ptr::copy_nonoverlapping(values.as_ptr(), buffer.buffer.memory, values.len());
+        }
+        buffer
     }
 
     pub(crate) fn handle(&self) -> BufferPoolHandle {
         self.handle
     }
+
+    /// Locks the native pool. Hold the guard across every native call that may allocate from or return memory to it.
+    pub(crate) fn lock(&self) -> MutexGuard<'_, ()> {
+        self.lock.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A typed buffer allocated from a [`BufferPool`], returned to the pool when dropped.
+///
+/// Dereferences to a slice of its elements. Elements are restricted to `Copy` types, since the pool hands out raw memory
+/// that the simulation may copy around without running destructors. Zero-sized elements are rejected at compile time.
+pub struct PooledBuffer<'pool, T: Copy> {
+    pool: &'pool BufferPool,
+    buffer: Buffer<T>,
+}
+
+impl<'pool, T: Copy> PooledBuffer<'pool, T> {
+    // Allocations are sized in bytes, so a buffer of zero-sized elements would never own memory to point at.
+    const NOT_ZERO_SIZED: () = assert!(
+        std::mem::size_of::<T>() != 0,
+        "pooled buffers can't hold zero-sized types"
+    );
+
+    fn empty(pool: &'pool BufferPool) -> Self {
+        let () = Self::NOT_ZERO_SIZED;
+        PooledBuffer {
+            pool,
+            buffer: Buffer::from_raw(std::ptr::null_mut(), 0, -1),
+        }
+    }
+
+    /// Gets the pool the buffer was allocated from.
+    pub fn pool(&self) -> &'pool BufferPool {
+        self.pool
+    }
+
+    /// Changes the size of the buffer, keeping the leading elements and default-initializing any new ones.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer would be larger than `i32::MAX` bytes.
+    pub fn resize(&mut self, len: usize)
+    where
+        T: Default,
+    {
+        let old_len = self.len();
+        // SAFETY:
+        //
+        // Elements past the old length are written below before they can be read.
+        unsafe {
+            self.reallocate(len);
+            for i in old_len..len {
+                self.buffer.memory.add(i).write(T::default());
+            }
+        }
+    }
+
+    /// Releases ownership of the memory, returning the raw buffer.
+    ///
+    /// Use this to hand the memory to a shape that takes ownership of it, like a mesh's triangles or a big compound's children.
+    /// The memory must eventually be returned to the same pool.
+    pub fn into_buffer(self) -> Buffer<T> {
+        let buffer = Buffer::from_raw(self.buffer.memory, self.buffer.len, self.buffer.id);
+        std::mem::forget(self);
+        buffer
+    }
+
+    /// Gets the raw buffer without releasing ownership of the memory.
+    pub(crate) fn as_raw(&self) -> Buffer<T> {
+        Buffer::from_raw(self.buffer.memory, self.buffer.len, self.buffer.id)
+    }
+
+    /// Resizes the underlying allocation, preserving the leading elements. New elements are left uninitialized.
+    unsafe fn reallocate(&mut self, len: usize) {
+        let size_in_bytes = len
+            .checked_mul(std::mem::size_of::<T>())
+            .and_then(|size| i32::try_from(size).ok())
+            .expect("pooled buffers are limited to i32::MAX bytes");
+        let copy_count = len.min(self.len()) * std::mem::size_of::<T>();
+        let mut raw = self.as_byte_buffer();
+        let _pool = self.pool.lock();
+        if len == 0 {
+            if self.is_allocated()
+                && !crate::ffi::buffer_pool::deallocate(self.pool.handle, &mut raw)
+            {
+                Error::panic_native("returning a pooled buffer");
+            }
+            self.buffer = Buffer::from_raw(std::ptr::null_mut(), 0, -1);
+            return;
+        }
+        if self.is_allocated() {
+            if !crate::ffi::buffer_pool::resize(
+                self.pool.handle,
+                &mut raw,
+                size_in_bytes,
+                copy_count as i32,
+            ) {
+                Error::panic_native("resizing a pooled buffer");
+            }
+        } else {
+            raw = crate::ffi::buffer_pool::allocate(self.pool.handle, size_in_bytes);
+            if raw.memory.is_null() {
+                Error::panic_native("allocating a pooled buffer");
+            }
+        }
+        debug_assert!(
+            (raw.memory as usize).is_multiple_of(std::mem::align_of::<T>()),
+            "buffer pool returned memory that is misaligned for the element type"
+        );
+        self.buffer = Buffer::from_raw(raw.memory as *mut T, len as i32, raw.id);
+    }
+
+    fn is_allocated(&self) -> bool {
+        !self.buffer.memory.is_null()
+    }
+
+    fn as_byte_buffer(&self) -> ByteBuffer {
+        ByteBuffer {
+            memory: self.buffer.memory as *mut u8,
+            len: self.buffer.len * std::mem::size_of::<T>() as i32,
+            id: self.buffer.id,
+        }
+    }
+}
+
+impl<T: Copy> Deref for PooledBuffer<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        if self.is_allocated() {
+            // SAFETY:
+            //
+            // The allocation holds `len` initialized elements and is owned by this buffer.
+            unsafe { std::slice::from_raw_parts(self.buffer.memory, self.buffer.len as usize) }
+        } else {
+            &[]
+        }
+    }
+}
+
+impl<T: Copy> DerefMut for PooledBuffer<'_, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        if self.is_allocated() {
+            // SAFETY: See `PooledBuffer::deref`. The buffer is uniquely borrowed.
+            unsafe { std::slice::from_raw_parts_mut(self.buffer.memory, self.buffer.len as usize) }
+        } else {
+            &mut []
+        }
+    }
+}
+
+impl<T: Copy> Drop for PooledBuffer<'_, T> {
+    fn drop(&mut self) {
+        if self.is_allocated() {
+            let mut raw = self.as_byte_buffer();
+            let _pool = self.pool.lock();
+            // SAFETY:
+            //
+            // The memory was allocated from this pool and ownership hasn't been released through `into_buffer`.
+            let returned =
+                unsafe { crate::ffi::buffer_pool::deallocate(self.pool.handle, &mut raw) };
+            if !returned && !std::thread::panicking() {
+                Error::panic_native("returning a pooled buffer");
+            }
+        }
+    }
 }
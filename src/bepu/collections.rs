@@ -0,0 +1,360 @@
+use std::ops::{Bound, Deref, DerefMut, RangeBounds};
+
+use crate::{
+    types::utilities::{Buffer, ByteBuffer, QuickList},
+    Error,
+};
+
+use super::buffer_pool::BufferPool;
+
+/// A growable list backed by memory from a [`BufferPool`], the owned counterpart of [`QuickList`].
+///
+/// Behaves like a `Vec` whose allocations come from the pool, so lists can be built in Rust and handed to Bepu
+/// without crossing into C# for each element. Dereferences to a slice of its elements.
+/// Elements are restricted to `Copy` types, since the pool hands out raw memory that the simulation may copy around
+/// without running destructors. Zero-sized elements are rejected at compile time.
+pub struct PooledList<'pool, T: Copy> {
+    pool: &'pool BufferPool,
+    list: QuickList<T>,
+}
+
+impl<'pool, T: Copy> PooledList<'pool, T> {
+    // Spans measure their length in bytes divided by the element size, which doesn't work for zero-sized elements.
+    const NOT_ZERO_SIZED: () = assert!(
+        std::mem::size_of::<T>() != 0,
+        "pooled lists can't hold zero-sized types"
+    );
+
+    /// Creates an empty list. No memory is taken from the pool until the first element is added.
+    pub fn new(pool: &'pool BufferPool) -> Self {
+        let () = Self::NOT_ZERO_SIZED;
+        PooledList {
+            pool,
+            list: QuickList {
+                span: Buffer::from_raw(std::ptr::null_mut(), 0, -1),
+                count: 0,
+            },
+        }
+    }
+
+    /// Creates an empty list with room for at least `capacity` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list would be larger than `i32::MAX` bytes.
+    pub fn with_capacity(pool: &'pool BufferPool, capacity: usize) -> Self {
+        let mut list = Self::new(pool);
+        list.reserve(capacity);
+        list
+    }
+
+    /// Takes ownership of a quick list allocated from `pool`.
+    ///
+    /// # Safety
+    ///
+    /// The list's span must either be unallocated or have been allocated from `pool`,
+    /// and nothing else may return it to the pool.
+    pub unsafe fn from_quick_list(pool: &'pool BufferPool, list: QuickList<T>) -> Self {
+        let () = Self::NOT_ZERO_SIZED;
+        PooledList { pool, list }
+    }
+
+    /// Releases ownership of the memory, returning the raw quick list.
+    ///
+    /// Use this to hand the list to FFI functions that take a [`QuickList`]. The memory must eventually be returned to
+    /// the same pool, for example by converting it back with [`PooledList::from_quick_list`].
+    pub fn into_quick_list(self) -> QuickList<T> {
+        let list = QuickList {
+            span: Buffer::from_raw(self.list.span.memory, self.list.span.len, self.list.span.id),
+            count: self.list.count,
+        };
+        std::mem::forget(self);
+        list
+    }
+
+    /// Gets the pool the list allocates from.
+    pub fn pool(&self) -> &'pool BufferPool {
+        self.pool
+    }
+
+    /// Gets the number of elements the list can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.list.span.len as usize
+    }
+
+    /// Makes sure the list can hold at least `additional` more elements without growing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list would be larger than `i32::MAX` bytes.
+    pub fn reserve(&mut self, additional: usize) {
+        let target = self
+            .len()
+            .checked_add(additional)
+            .expect("pooled lists are limited to i32::MAX bytes");
+        if target > self.capacity() {
+            self.grow(target);
+        }
+    }
+
+    /// Appends an element to the end of the list.
+    pub fn push(&mut self, value: T) {
+        self.reserve(1);
+        // SAFETY:
+        //
+        // The reservation above guarantees the slot past the last element is allocated.
+        unsafe { self.list.span.memory.add(self.len()).write(value) };
+        self.list.count += 1;
+    }
+
+    /// Removes the last element of the list and returns it, or `None` if the list is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.list.count == 0 {
+            return None;
+        }
+        self.list.count -= 1;
+        // SAFETY:
+        //
+        // The slot was part of the list's initialized elements before the count was decremented.
+        Some(unsafe { self.list.span.memory.add(self.len()).read() })
+    }
+
+    /// Appends every element of a slice to the end of the list.
+    pub fn extend_from_slice(&mut self, values: &[T]) {
+        self.reserve(values.len());
+        // SAFETY:
+        //
+        // The reservation above guarantees room for every value past the last element.
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                values.as_ptr(),
+                self.list.span.memory.add(self.len()),
+                values.len(),
+            );
+        }
+        self.list.count += values.len() as i32;
+    }
+
+    /// Removes every element in `range` from the list, returning them as an iterator.
+    ///
+    /// The elements after the range are shifted down when the iterator is dropped, even if it wasn't fully consumed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or its start is past its end.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, 'pool, T> {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+        assert!(
+            start <= end && end <= len,
+            "drain range {start}..{end} is out of bounds for a list of length {len}"
+        );
+        // Elements in the range and after it are hidden until the drain is dropped,
+        // so leaking the drain only loses elements instead of exposing drained ones.
+        self.list.count = start as i32;
+        Drain {
+            list: self,
+            next: start,
+            end,
+            tail_start: end,
+            tail_len: len - end,
+        }
+    }
+
+    /// Removes every element from the list, keeping its memory.
+    pub fn clear(&mut self) {
+        self.list.count = 0;
+    }
+
+    fn grow(&mut self, target: usize) {
+        let size_in_bytes = target
+            .checked_mul(std::mem::size_of::<T>())
+            .and_then(|size| i32::try_from(size).ok())
+            .expect("pooled lists are limited to i32::MAX bytes");
+        let mut raw = ByteBuffer::from(Buffer::from_raw(
+            self.list.span.memory,
+            self.list.span.len,
+            self.list.span.id,
+        ));
+        let _pool = self.pool.lock();
+        // SAFETY:
+        //
+        // The span is either unallocated or was allocated from this list's pool.
+        // Only the initialized elements are copied into the new allocation.
+        unsafe {
+            if self.list.span.memory.is_null() {
+                raw = crate::ffi::buffer_pool::allocate_at_least(self.pool.handle(), size_in_bytes);
+                if raw.memory.is_null() {
+                    Error::panic_native("allocating a pooled list");
+                }
+            } else if !crate::ffi::buffer_pool::resize_to_at_least(
+                self.pool.handle(),
+                &mut raw,
+                size_in_bytes,
+                self.list.count * std::mem::size_of::<T>() as i32,
+            ) {
+                Error::panic_native("growing a pooled list");
+            }
+        }
+        debug_assert!(
+            (raw.memory as usize).is_multiple_of(std::mem::align_of::<T>()),
+            "buffer pool returned memory that is misaligned for the element type"
+        );
+        self.list.span = Buffer::from(raw);
+    }
+}
+
+impl<T: Copy> Deref for PooledList<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        if self.list.span.memory.is_null() {
+            &[]
+        } else {
+            // SAFETY:
+            //
+            // The first `count` elements of the span are initialized and owned by this list.
+            unsafe { std::slice::from_raw_parts(self.list.span.memory, self.list.count as usize) }
+        }
+    }
+}
+
+impl<T: Copy> DerefMut for PooledList<'_, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        if self.list.span.memory.is_null() {
+            &mut []
+        } else {
+            // SAFETY: See `PooledList::deref`. The list is uniquely borrowed.
+            unsafe {
+                std::slice::from_raw_parts_mut(self.list.span.memory, self.list.count as usize)
+            }
+        }
+    }
+}
+
+impl<T: Copy> Extend<T> for PooledList<'_, T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for PooledList<'_, T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<'a, T: Copy> IntoIterator for &'a PooledList<'_, T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: Copy> IntoIterator for &'a mut PooledList<'_, T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: Copy> Drop for PooledList<'_, T> {
+    fn drop(&mut self) {
+        if !self.list.span.memory.is_null() {
+            let mut raw = ByteBuffer::from(Buffer::from_raw(
+                self.list.span.memory,
+                self.list.span.len,
+                self.list.span.id,
+            ));
+            let _pool = self.pool.lock();
+            // SAFETY:
+            //
+            // The memory was allocated from this pool and ownership hasn't been released through `into_quick_list`.
+            let returned =
+                unsafe { crate::ffi::buffer_pool::deallocate(self.pool.handle(), &mut raw) };
+            if !returned && !std::thread::panicking() {
+                Error::panic_native("returning a pooled list");
+            }
+        }
+    }
+}
+
+/// An iterator over the elements removed from a [`PooledList`] by [`PooledList::drain`].
+pub struct Drain<'a, 'pool, T: Copy> {
+    list: &'a mut PooledList<'pool, T>,
+    next: usize,
+    end: usize,
+    tail_start: usize,
+    tail_len: usize,
+}
+
+impl<T: Copy> Iterator for Drain<'_, '_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.next == self.end {
+            return None;
+        }
+        // SAFETY:
+        //
+        // Drained elements stay initialized in the span until the tail is shifted over them on drop.
+        let value = unsafe { self.list.list.span.memory.add(self.next).read() };
+        self.next += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: Copy> DoubleEndedIterator for Drain<'_, '_, T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.next == self.end {
+            return None;
+        }
+        self.end -= 1;
+        // SAFETY: See `Drain::next`.
+        Some(unsafe { self.list.list.span.memory.add(self.end).read() })
+    }
+}
+
+impl<T: Copy> ExactSizeIterator for Drain<'_, '_, T> {}
+
+impl<T: Copy> Drop for Drain<'_, '_, T> {
+    fn drop(&mut self) {
+        let start = self.list.len();
+        if self.tail_len > 0 {
+            // SAFETY:
+            //
+            // Both ranges lie within the list's initialized elements from before the drain; they may overlap.
+            unsafe {
+                let memory = self.list.list.span.memory;
+                std::ptr::copy(
+                    memory.add(self.tail_start),
+                    memory.add(start),
+                    self.tail_len,
+                );
+            }
+        }
+        self.list.list.count = (start + self.tail_len) as i32;
+    }
+}
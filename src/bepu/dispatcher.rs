@@ -1,7 +1,73 @@
-use crate::types::handles::ThreadDispatcherHandle;
+use std::{cell::Cell, marker::PhantomData};
 
+use crate::{types::handles::ThreadDispatcherHandle, Error};
+
+use super::memory::MemoryReport;
+
+/// A job system that Bepu can hand its multithreaded work to.
+///
+/// Implement this to run physics on an engine's existing scheduler instead of threads owned by Bepu.
+/// With the `rayon` feature enabled it is implemented for [`rayon::ThreadPool`].
+///
+/// # Safety
+///
+/// [`JobDispatcher::dispatch`] must run every worker index in `0..worker_count` exactly once, with all of them
+/// able to run concurrently, and must only return after every worker has finished.
+/// Bepu synchronizes workers with each other during a step, so running them one after another on fewer threads deadlocks,
+/// and returning early lets the step free memory the remaining workers are still using.
+pub unsafe trait JobDispatcher: Send + Sync + 'static {
+    /// Gets the maximum number of workers that can run concurrently.
+    fn thread_count(&self) -> usize;
+
+    /// Runs `job` once for every worker index in `0..worker_count` and returns once all of them have completed.
+    ///
+    /// `worker_count` never exceeds [`JobDispatcher::thread_count`].
+    fn dispatch(&self, worker_count: usize, job: &(dyn Fn(usize) + Sync));
+}
+
+// SAFETY:
+//
+// Forwards to the shared job system, which upholds the contract itself.
+unsafe impl<J: JobDispatcher> JobDispatcher for std::sync::Arc<J> {
+    fn thread_count(&self) -> usize {
+        (**self).thread_count()
+    }
+
+    fn dispatch(&self, worker_count: usize, job: &(dyn Fn(usize) + Sync)) {
+        (**self).dispatch(worker_count, job)
+    }
+}
+
+// SAFETY:
+//
+// Broadcasting runs the closure once on every thread of the pool at the same time and waits for all of them,
+// and `worker_count` never exceeds the number of threads.
+#[cfg(feature = "rayon")]
+unsafe impl JobDispatcher for rayon::ThreadPool {
+    fn thread_count(&self) -> usize {
+        self.current_num_threads()
+    }
+
+    fn dispatch(&self, worker_count: usize, job: &(dyn Fn(usize) + Sync)) {
+        self.broadcast(|context| {
+            if context.index() < worker_count {
+                job(context.index());
+            }
+        });
+    }
+}
+
+/// A dispatcher Bepu spreads the work of a step over.
+///
+/// The dispatcher can be moved between threads but not shared between them:
+/// Bepu's dispatchers keep the state of the job in flight, so two steps must never dispatch on one at the same time.
 pub struct ThreadDispatcher {
     handle: ThreadDispatcherHandle,
+    /// Job system the native dispatcher forwards to, if it was created with one.
+    ///
+    /// Boxed so its address stays stable. It is dropped after the native dispatcher is destroyed in `Drop::drop`.
+    jobs: Option<Box<dyn JobDispatcher>>,
+    _not_sync: PhantomData<Cell<()>>,
 }
 
 impl Drop for ThreadDispatcher {
@@ -10,23 +76,153 @@ impl Drop for ThreadDispatcher {
         //
         // The only place to obtain a buffer pool handle is the constructor of this type.
         // That constructor ties the handle's lifetime to 'self' so when 'self' is dropped there are no references to handle.
-        unsafe {
-            crate::ffi::dispatcher::destroy_thread_dispatcher(self.handle);
+        let destroyed = unsafe { crate::ffi::dispatcher::destroy_thread_dispatcher(self.handle) };
+        if !destroyed && !std::thread::panicking() {
+            Error::panic_native("destroying a thread dispatcher");
         }
     }
 }
 
 impl ThreadDispatcher {
+    /// Creates a dispatcher with its own worker threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if Bepu rejects the settings; see [`ThreadDispatcher::try_new`].
     pub fn new(thread_count: i32, alloc_block_size: i32) -> Self {
-        ThreadDispatcher {
-            // SAFETY:
-            //
-            // Creating a handle is always safe as long as you use it responsibly.
-            // `crate::ffi::buffer_pool::destroy_thread_dispatcher` needs to be called for every handle created.
-            // This is handled in the 'Drop' impl for `ThreadDispatcher`
-            handle: unsafe {
-                crate::ffi::dispatcher::create_thread_dispatcher(thread_count, alloc_block_size)
-            },
+        Self::try_new(thread_count, alloc_block_size)
+            .unwrap_or_else(|error| panic!("failed to create thread dispatcher: {error}"))
+    }
+
+    /// Creates a dispatcher with its own worker threads, returning an error if Bepu rejects the settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `thread_count`: Number of threads to use within the thread dispatcher.
+    /// * `alloc_block_size`: Minimum size in bytes of blocks allocated in per-worker buffer pools.
+    pub fn try_new(thread_count: i32, alloc_block_size: i32) -> Result<Self, Error> {
+        // SAFETY:
+        //
+        // Creating a handle is always safe as long as you use it responsibly.
+        // `crate::ffi::buffer_pool::destroy_thread_dispatcher` needs to be called for every handle created.
+        // This is handled in the 'Drop' impl for `ThreadDispatcher`
+        let handle = unsafe {
+            crate::ffi::dispatcher::create_thread_dispatcher(thread_count, alloc_block_size)
+        };
+        if handle.is_null() {
+            return Err(Error::take_native());
+        }
+        Ok(ThreadDispatcher {
+            handle,
+            jobs: None,
+            _not_sync: PhantomData,
+        })
+    }
+
+    /// Creates a dispatcher that runs Bepu's workers on a Rust job system instead of its own threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if Bepu rejects the settings; see [`ThreadDispatcher::try_with_jobs`].
+    pub fn with_jobs<J: JobDispatcher>(jobs: J, alloc_block_size: i32) -> Self {
+        Self::try_with_jobs(jobs, alloc_block_size)
+            .unwrap_or_else(|error| panic!("failed to create thread dispatcher: {error}"))
+    }
+
+    /// Creates a dispatcher that runs Bepu's workers on a Rust job system, returning an error if Bepu rejects the settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `jobs`: Job system to run workers on. One worker buffer pool is created for each of its threads.
+    /// * `alloc_block_size`: Minimum size in bytes of blocks allocated in per-worker buffer pools.
+    pub fn try_with_jobs<J: JobDispatcher>(jobs: J, alloc_block_size: i32) -> Result<Self, Error> {
+        let jobs = Box::new(jobs);
+        let thread_count = jobs.thread_count() as i32;
+        // SAFETY:
+        //
+        // The job system is boxed and owned by the returned dispatcher, which destroys the native dispatcher before dropping it.
+        let handle = unsafe {
+            crate::ffi::dispatcher::create_custom_thread_dispatcher(
+                thread_count,
+                alloc_block_size,
+                &*jobs as *const J as *mut (),
+                dispatch::<J>,
+            )
+        };
+        if handle.is_null() {
+            return Err(Error::take_native());
+        }
+        Ok(ThreadDispatcher {
+            handle,
+            jobs: Some(jobs),
+            _not_sync: PhantomData,
+        })
+    }
+
+    /// Gets the job system the dispatcher forwards to, if it was created with [`ThreadDispatcher::with_jobs`].
+    pub fn jobs(&self) -> Option<&dyn JobDispatcher> {
+        self.jobs.as_deref()
+    }
+
+    /// Gets the number of workers the dispatcher can run, which is also the number of worker buffer pools.
+    pub fn thread_count(&self) -> i32 {
+        // SAFETY: See `ThreadDispatcher::new`.
+        let thread_count = unsafe { crate::ffi::dispatcher::get_thread_count(self.handle) };
+        if thread_count < 0 {
+            Error::panic_native("reading a dispatcher's thread count");
+        }
+        thread_count
+    }
+
+    /// Gets the number of bytes allocated from native memory across all worker buffer pools.
+    pub fn allocated_memory(&self) -> u64 {
+        let mut size = 0;
+        // SAFETY: See `ThreadDispatcher::new`.
+        let measured = unsafe {
+            crate::ffi::dispatcher::get_allocated_memory_size_in_thread_dispatcher(
+                self.handle,
+                &mut size,
+            )
+        };
+        if !measured {
+            Error::panic_native("measuring a thread dispatcher");
+        }
+        size
+    }
+
+    /// Gets the number of bytes allocated from native memory in a single worker's buffer pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `worker_index` is not below [`ThreadDispatcher::thread_count`].
+    pub fn worker_allocated_memory(&self, worker_index: i32) -> u64 {
+        assert!(
+            worker_index >= 0 && worker_index < self.thread_count(),
+            "worker index out of range"
+        );
+        let mut size = 0;
+        // SAFETY: See `ThreadDispatcher::new`. The worker index was checked above.
+        let measured = unsafe {
+            crate::ffi::dispatcher::get_worker_allocated_memory_size(
+                self.handle,
+                worker_index,
+                &mut size,
+            )
+        };
+        if !measured {
+            Error::panic_native("measuring a worker buffer pool");
+        }
+        size
+    }
+
+    /// Gets a report of the native memory held by the dispatcher's worker buffer pools.
+    pub fn memory_report(&self) -> MemoryReport {
+        MemoryReport {
+            native_bytes: self.allocated_memory(),
+            worker_native_bytes: (0..self.thread_count())
+                .map(|worker_index| self.worker_allocated_memory(worker_index))
+                .collect(),
+            ..Default::default()
         }
     }
 
@@ -34,3 +230,39 @@ impl ThreadDispatcher {
         self.handle
     }
 }
+
+/// Worker body handed over by the native dispatcher. Only ever called while the dispatch that produced it is in flight.
+#[derive(Clone, Copy)]
+struct WorkerBody {
+    function: unsafe extern "C" fn(worker_context: *mut (), worker_index: i32),
+    context: *mut (),
+}
+
+// SAFETY:
+//
+// The native worker body is written to be run from many threads at once; that is the whole point of dispatching it.
+unsafe impl Send for WorkerBody {}
+unsafe impl Sync for WorkerBody {}
+
+impl WorkerBody {
+    fn run(&self, worker_index: usize) {
+        // SAFETY: See `WorkerBody`.
+        unsafe { (self.function)(self.context, worker_index as i32) }
+    }
+}
+
+unsafe extern "C" fn dispatch<J: JobDispatcher>(
+    context: *mut (),
+    worker_count: i32,
+    worker_body: unsafe extern "C" fn(worker_context: *mut (), worker_index: i32),
+    worker_context: *mut (),
+) {
+    let body = WorkerBody {
+        function: worker_body,
+        context: worker_context,
+    };
+    // Only installed by `ThreadDispatcher::with_jobs`, so the context points to the live job system.
+    (*(context as *const J)).dispatch(worker_count as usize, &move |worker_index| {
+        body.run(worker_index)
+    });
+}
@@ -0,0 +1,24 @@
+/// A snapshot of the memory held by an allocator.
+///
+/// Obtained from [`BufferPool::memory_report`](super::buffer_pool::BufferPool::memory_report),
+/// [`ThreadDispatcher::memory_report`](super::dispatcher::ThreadDispatcher::memory_report) and [`runtime_memory`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryReport {
+    /// Bytes allocated from native memory, including memory not currently handed out.
+    pub native_bytes: u64,
+    /// Estimated bytes allocated from the managed heap of the .NET runtime.
+    pub managed_bytes: u64,
+    /// Bytes allocated from native memory by each worker's buffer pool. Only filled in for thread dispatchers.
+    pub worker_native_bytes: Vec<u64>,
+}
+
+/// Gets the memory held by the .NET runtime hosting Bepu itself, as opposed to any single pool.
+pub fn runtime_memory() -> MemoryReport {
+    MemoryReport {
+        // SAFETY:
+        //
+        // Querying the garbage collector has no preconditions.
+        managed_bytes: unsafe { crate::ffi::utils::get_gc_allocated_memory_size() },
+        ..Default::default()
+    }
+}
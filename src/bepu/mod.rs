@@ -1,5 +1,7 @@
 //! This module holds user facing logic that ensures safety accross ffi
 
 pub mod buffer_pool;
+pub mod collections;
 pub mod dispatcher;
+pub mod memory;
 pub mod simulation;
@@ -0,0 +1,353 @@
+use std::mem::MaybeUninit;
+
+use crate::{
+    types::{
+        body::{
+            BodyActivity, BodyActivityDescription, BodyDescription, BodyDynamics, BodyInertia,
+            BodyVelocity, Collidable, MotionState,
+        },
+        ccd::ContinuousDetection,
+        handles::BodyHandle,
+        math::scalar::RigidPose,
+        simulation::BodyMemoryLocation,
+        utilities::Buffer,
+    },
+    Error,
+};
+
+use super::Simulation;
+
+impl Simulation {
+    /// Adds a body to the simulation, returning a handle that can be used to access it later.
+    ///
+    /// returns: The handle of the body, or the error Bepu raised while adding it.
+    /// A shape that doesn't exist in this simulation is an [`Error::InvalidHandle`].
+    pub fn add_body(&mut self, description: BodyDescription) -> Result<BodyHandle, Error> {
+        self.validate_optional_shape(description.collidable.shape)?;
+        let _pool = self.pool.lock();
+        // SAFETY:
+        //
+        // The simulation handle is owned by `self` and is valid for as long as `self` is alive.
+        let handle = unsafe { crate::ffi::simulation::add_body(self.handle, description) };
+        if handle.value < 0 {
+            return Err(Error::take_native());
+        }
+        Ok(handle)
+    }
+
+    /// Removes a body from the simulation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle does not refer to a body in this simulation.
+    pub fn remove_body(&mut self, handle: BodyHandle) {
+        assert!(
+            self.contains_body(handle),
+            "body handle does not exist in this simulation"
+        );
+        let _pool = self.pool.lock();
+        // SAFETY:
+        //
+        // The handle was checked against the simulation's handle mapping above.
+        let removed = unsafe { crate::ffi::simulation::remove_body(self.handle, handle) };
+        if !removed {
+            Error::panic_native("removing a body");
+        }
+    }
+
+    /// Gets whether the handle refers to a body that currently exists in the simulation.
+    pub fn contains_body(&self, handle: BodyHandle) -> bool {
+        let mut mapping = Buffer::<BodyMemoryLocation>::new();
+        // SAFETY:
+        //
+        // The mapping buffer is only read before any further calls into the simulation,
+        // so it can't be invalidated by a resize while in use.
+        let read = unsafe {
+            crate::ffi::simulation::get_body_handle_to_location_mapping(self.handle, &mut mapping)
+        };
+        if !read {
+            Error::panic_native("reading the body handle mapping");
+        }
+        // Removed bodies and never-allocated slots both have a negative set index.
+        handle.value >= 0 && handle.value < mapping.len && mapping[handle.value].set_index >= 0
+    }
+
+    /// Gets a read-only view of a body.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle does not refer to a body in this simulation.
+    pub fn body(&self, handle: BodyHandle) -> BodyRef<'_> {
+        assert!(
+            self.contains_body(handle),
+            "body handle does not exist in this simulation"
+        );
+        BodyRef {
+            simulation: self,
+            handle,
+        }
+    }
+
+    /// Gets a mutable view of a body.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle does not refer to a body in this simulation.
+    pub fn body_mut(&mut self, handle: BodyHandle) -> BodyMut<'_> {
+        assert!(
+            self.contains_body(handle),
+            "body handle does not exist in this simulation"
+        );
+        BodyMut {
+            simulation: self,
+            handle,
+        }
+    }
+
+    /// Gets a pointer to the dynamic state of a body.
+    ///
+    /// # Safety
+    ///
+    /// The handle must refer to a body in this simulation.
+    unsafe fn body_dynamics(&self, handle: BodyHandle) -> *mut BodyDynamics {
+        let dynamics = crate::ffi::simulation::get_body_dynamics(self.handle, handle);
+        if dynamics.is_null() {
+            Error::panic_native("reading a body's dynamics");
+        }
+        dynamics
+    }
+
+    /// Gets a pointer to the collidable of a body.
+    ///
+    /// # Safety
+    ///
+    /// The handle must refer to a body in this simulation.
+    unsafe fn body_collidable(&self, handle: BodyHandle) -> *mut Collidable {
+        let collidable = crate::ffi::simulation::get_body_collidable(self.handle, handle);
+        if collidable.is_null() {
+            Error::panic_native("reading a body's collidable");
+        }
+        collidable
+    }
+
+    /// Gets a pointer to the sleeping state of a body.
+    ///
+    /// # Safety
+    ///
+    /// The handle must refer to a body in this simulation.
+    unsafe fn body_activity(&self, handle: BodyHandle) -> *mut BodyActivity {
+        let activity = crate::ffi::simulation::get_body_activity(self.handle, handle);
+        if activity.is_null() {
+            Error::panic_native("reading a body's activity");
+        }
+        activity
+    }
+}
+
+/// A read-only view of a body living in a [`Simulation`].
+///
+/// The view borrows the simulation, so the memory it points to can't be moved by adding or removing bodies while it is alive.
+pub struct BodyRef<'a> {
+    simulation: &'a Simulation,
+    handle: BodyHandle,
+}
+
+impl<'a> BodyRef<'a> {
+    /// Gets the handle of the body.
+    pub fn handle(&self) -> BodyHandle {
+        self.handle
+    }
+
+    /// Gets the pose, velocity and inertia of the body.
+    pub fn dynamics(&self) -> &'a BodyDynamics {
+        // SAFETY:
+        //
+        // The handle was validated when the view was created and the simulation is immutably borrowed for 'a,
+        // so the body can't be removed or moved in memory while the reference is alive.
+        unsafe { &*self.simulation.body_dynamics(self.handle) }
+    }
+
+    /// Gets the collidable of the body.
+    pub fn collidable(&self) -> &'a Collidable {
+        // SAFETY: See `BodyRef::dynamics`.
+        unsafe { &*self.simulation.body_collidable(self.handle) }
+    }
+
+    /// Gets the sleeping state of the body.
+    pub fn activity(&self) -> &'a BodyActivity {
+        // SAFETY: See `BodyRef::dynamics`.
+        unsafe { &*self.simulation.body_activity(self.handle) }
+    }
+
+    /// Gets the pose of the body.
+    pub fn pose(&self) -> &'a RigidPose {
+        &self.dynamics().motion.pose
+    }
+
+    /// Gets the velocity of the body.
+    pub fn velocity(&self) -> &'a BodyVelocity {
+        &self.dynamics().motion.velocity
+    }
+
+    /// Gets the inertia of the body in its local space.
+    pub fn local_inertia(&self) -> &'a BodyInertia {
+        &self.dynamics().inertia.local
+    }
+
+    /// Gets whether the body is kinematic, meaning it has infinite mass and inertia.
+    pub fn is_kinematic(&self) -> bool {
+        let inertia = self.local_inertia();
+        let tensor = &inertia.inverse_inertia_tensor;
+        inertia.inverse_mass == 0.0
+            && tensor.xx == 0.0
+            && tensor.yx == 0.0
+            && tensor.yy == 0.0
+            && tensor.zx == 0.0
+            && tensor.zy == 0.0
+            && tensor.zz == 0.0
+    }
+
+    /// Builds a description of the body's current state.
+    pub fn description(&self) -> BodyDescription {
+        let mut description = MaybeUninit::<BodyDescription>::uninit();
+        // SAFETY: See `BodyRef::dynamics`. The description is only read once the call reported success.
+        unsafe {
+            if !crate::ffi::simulation::get_body_description(
+                self.simulation.handle,
+                self.handle,
+                description.as_mut_ptr(),
+            ) {
+                Error::panic_native("reading a body's description");
+            }
+            description.assume_init()
+        }
+    }
+}
+
+/// A mutable view of a body living in a [`Simulation`].
+///
+/// The view uniquely borrows the simulation, so references handed out by it are invalidated by the borrow checker
+/// before anything could move the body in memory.
+pub struct BodyMut<'a> {
+    simulation: &'a mut Simulation,
+    handle: BodyHandle,
+}
+
+impl<'a> BodyMut<'a> {
+    /// Gets the handle of the body.
+    pub fn handle(&self) -> BodyHandle {
+        self.handle
+    }
+
+    /// Reborrows this view as a read-only view.
+    pub fn as_body_ref(&self) -> BodyRef<'_> {
+        BodyRef {
+            simulation: self.simulation,
+            handle: self.handle,
+        }
+    }
+
+    /// Gets the pose and velocity of the body.
+    ///
+    /// The inertia isn't exposed mutably, since changing it can move the body between the kinematic and dynamic states;
+    /// use [`BodyMut::set_local_inertia`] instead. Likewise, the shape can only be changed through [`BodyMut::apply_description`],
+    /// which notifies the broad phase.
+    pub fn motion(&mut self) -> &mut MotionState {
+        // SAFETY:
+        //
+        // The handle was validated when the view was created and the returned reference borrows `self` uniquely,
+        // so nothing can remove or move the body while the reference is alive.
+        unsafe { &mut (*self.simulation.body_dynamics(self.handle)).motion }
+    }
+
+    /// Sets the continuous collision detection settings of the body.
+    pub fn set_continuity(&mut self, continuity: ContinuousDetection) {
+        // SAFETY: See `BodyMut::motion`. Bepu reads the continuity settings fresh every step, so they can be written directly.
+        unsafe {
+            (*self.simulation.body_collidable(self.handle)).continuity = continuity;
+        }
+    }
+
+    /// Sets the bounds of the speculative margin used by the body.
+    ///
+    /// # Arguments
+    ///
+    /// * `minimum`: Lower bound on the speculative margin. 0 tends to be a good default.
+    /// * `maximum`: Upper bound on the speculative margin. `f32::MAX` tends to be a good default.
+    pub fn set_speculative_margins(&mut self, minimum: f32, maximum: f32) {
+        // SAFETY: See `BodyMut::set_continuity`.
+        unsafe {
+            let collidable = &mut *self.simulation.body_collidable(self.handle);
+            collidable.minimum_speculative_margin = minimum;
+            collidable.maximum_speculative_margin = maximum;
+        }
+    }
+
+    /// Sets when the body is allowed to go to sleep.
+    ///
+    /// The rest of the body's sleeping state is managed by Bepu and is only exposed through [`BodyRef::activity`].
+    pub fn set_activity(&mut self, activity: BodyActivityDescription) {
+        // SAFETY: See `BodyMut::motion`. The sleep threshold and minimum timestep count are only read when deciding whether to sleep.
+        unsafe {
+            let current = &mut *self.simulation.body_activity(self.handle);
+            current.sleep_threshold = activity.sleep_threshold;
+            current.minimum_timesteps_under_threshold =
+                activity.minimum_timestep_count_under_threshold;
+        }
+    }
+
+    /// Gets the pose of the body.
+    pub fn pose(&mut self) -> &mut RigidPose {
+        &mut self.motion().pose
+    }
+
+    /// Gets the velocity of the body.
+    pub fn velocity(&mut self) -> &mut BodyVelocity {
+        &mut self.motion().velocity
+    }
+
+    /// Sets the local inertia of the body, waking it.
+    ///
+    /// Switching between zero and nonzero inverse mass moves the body between the kinematic and dynamic states,
+    /// which updates the constraints and islands the body belongs to.
+    pub fn set_local_inertia(&mut self, local_inertia: BodyInertia) {
+        // SAFETY:
+        //
+        // The handle was validated when the view was created and the simulation is uniquely borrowed.
+        let set = unsafe {
+            crate::ffi::simulation::set_body_local_inertia(
+                self.simulation.handle,
+                self.handle,
+                local_inertia,
+            )
+        };
+        if !set {
+            Error::panic_native("setting a body's inertia");
+        }
+    }
+
+    /// Applies a description to the body, updating every property and notifying the broad phase of any shape change.
+    ///
+    /// returns: The error Bepu raised while applying the description, if any.
+    /// A shape that doesn't exist in the simulation is an [`Error::InvalidHandle`].
+    pub fn apply_description(&mut self, description: BodyDescription) -> Result<(), Error> {
+        self.simulation
+            .validate_optional_shape(description.collidable.shape)?;
+        let _pool = self.simulation.pool.lock();
+        // SAFETY:
+        //
+        // The handle was validated when the view was created and the simulation is uniquely borrowed.
+        let applied = unsafe {
+            crate::ffi::simulation::apply_body_description(
+                self.simulation.handle,
+                self.handle,
+                description,
+            )
+        };
+        if applied {
+            Ok(())
+        } else {
+            Err(Error::take_native())
+        }
+    }
+}
@@ -0,0 +1,543 @@
+use std::{fmt, sync::Arc};
+
+use crate::{
+    bepu::{buffer_pool::BufferPool, dispatcher::ThreadDispatcher},
+    types::{
+        math::scalar::Vector3,
+        simulation::{SimulationAllocationSizes, SolveDescription},
+    },
+    Error,
+};
+
+use super::{
+    callbacks::{
+        DefaultNarrowPhaseHandler, DefaultPoseIntegrator, NarrowPhaseHandler, PoseIntegrator,
+    },
+    schedule::IterationSchedule,
+    Simulation,
+};
+
+/// Minimum block size of the buffer pool created by default, matching `BufferPool::default`.
+const DEFAULT_POOL_BLOCK_SIZE: i32 = 131072;
+/// Expected used slot count per power pool of the buffer pool created by default, matching `BufferPool::default`.
+const DEFAULT_POOL_EXPECTED_SLOT_COUNT: i32 = 16;
+/// Block size used for the worker pools of the thread dispatcher created by default, matching Bepu's own default.
+const DEFAULT_WORKER_BLOCK_SIZE: i32 = 16384;
+
+/// How the built simulation gets its thread dispatcher.
+enum DispatcherSetting {
+    /// Create a dispatcher owned by the simulation, with one worker per platform thread unless a count is given.
+    Owned {
+        thread_count: Option<i32>,
+    },
+    /// Hand a dispatcher created by the caller to the simulation.
+    Given(ThreadDispatcher),
+    SingleThreaded,
+}
+
+/// An invalid setting found by [`SimulationBuilder::build`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The number of substeps must be at least 1.
+    SubstepCount(i32),
+    /// The number of velocity iterations must be at least 1.
+    VelocityIterationCount(i32),
+    /// Velocity iteration schedulers must be set through [`SimulationBuilder::iteration_schedule`],
+    /// not the raw solve description.
+    RawScheduler,
+    /// The fallback batch threshold must be at least 1.
+    FallbackBatchThreshold(i32),
+    /// Allocation sizes can't be negative.
+    AllocationSize {
+        /// Name of the offending field of [`SimulationAllocationSizes`].
+        field: &'static str,
+        /// The negative size that was given.
+        value: i32,
+    },
+    /// Buffer pool block sizes must be positive powers of two.
+    BlockSize(i32),
+    /// Buffer pools must expect at least one used slot per power pool.
+    ExpectedSlotCount(i32),
+    /// A thread dispatcher needs at least one thread.
+    ThreadCount(i32),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::SubstepCount(value) => {
+                write!(f, "substep count must be at least 1, got {value}")
+            }
+            ConfigError::VelocityIterationCount(value) => {
+                write!(
+                    f,
+                    "velocity iteration count must be at least 1, got {value}"
+                )
+            }
+            ConfigError::RawScheduler => write!(
+                f,
+                "velocity iteration schedulers must be set through `SimulationBuilder::iteration_schedule`"
+            ),
+            ConfigError::FallbackBatchThreshold(value) => {
+                write!(
+                    f,
+                    "fallback batch threshold must be at least 1, got {value}"
+                )
+            }
+            ConfigError::AllocationSize { field, value } => {
+                write!(
+                    f,
+                    "allocation size `{field}` can't be negative, got {value}"
+                )
+            }
+            ConfigError::BlockSize(value) => {
+                write!(f, "block size must be a positive power of two, got {value}")
+            }
+            ConfigError::ExpectedSlotCount(value) => {
+                write!(
+                    f,
+                    "expected used slot count must be at least 1, got {value}"
+                )
+            }
+            ConfigError::ThreadCount(value) => {
+                write!(f, "thread count must be at least 1, got {value}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Builds a [`Simulation`] along with the resources it needs.
+///
+/// By default the simulation owns a fresh [`BufferPool`] and a [`ThreadDispatcher`] with one worker per platform thread,
+/// so nothing can be dropped out from under it. The pool can be replaced by a shared one, and the dispatcher by one
+/// created by the caller.
+///
+/// Settings are validated when the simulation is built, so mistakes surface as a [`ConfigError`] instead of a crash in Bepu.
+pub struct SimulationBuilder<H = DefaultNarrowPhaseHandler, P = DefaultPoseIntegrator> {
+    pool: Option<Arc<BufferPool>>,
+    pool_block_size: i32,
+    pool_expected_slot_count: i32,
+    thread_dispatcher: DispatcherSetting,
+    worker_block_size: i32,
+    narrow_phase: H,
+    pose_integrator: P,
+    solve_description: SolveDescription,
+    iteration_schedule: Option<IterationSchedule>,
+    allocation_sizes: SimulationAllocationSizes,
+}
+
+impl SimulationBuilder {
+    /// Creates a builder with the default handlers, solver settings and allocation sizes.
+    pub fn new() -> Self {
+        SimulationBuilder {
+            pool: None,
+            pool_block_size: DEFAULT_POOL_BLOCK_SIZE,
+            pool_expected_slot_count: DEFAULT_POOL_EXPECTED_SLOT_COUNT,
+            thread_dispatcher: DispatcherSetting::Owned { thread_count: None },
+            worker_block_size: DEFAULT_WORKER_BLOCK_SIZE,
+            narrow_phase: DefaultNarrowPhaseHandler,
+            pose_integrator: DefaultPoseIntegrator::default(),
+            solve_description: SolveDescription::default(),
+            iteration_schedule: None,
+            allocation_sizes: SimulationAllocationSizes::default(),
+        }
+    }
+}
+
+impl Default for SimulationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H: NarrowPhaseHandler, P: PoseIntegrator> SimulationBuilder<H, P> {
+    /// Allocates from a shared buffer pool instead of creating one for the simulation.
+    ///
+    /// Simulations sharing a pool can live on different threads; they take turns on the pool's lock.
+    pub fn pool(mut self, pool: Arc<BufferPool>) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Sets the settings of the buffer pool created for the simulation. Ignored if a shared pool is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `minimum_block_size`: Minimum size in bytes of blocks allocated from native memory. Must be a power of two.
+    /// * `expected_slot_count`: Number of allocations expected per power pool.
+    pub fn pool_settings(mut self, minimum_block_size: i32, expected_slot_count: i32) -> Self {
+        self.pool_block_size = minimum_block_size;
+        self.pool_expected_slot_count = expected_slot_count;
+        self
+    }
+
+    /// Sets the number of threads of the thread dispatcher created for the simulation.
+    /// Defaults to the number of threads exposed by the platform.
+    pub fn thread_count(mut self, thread_count: i32) -> Self {
+        self.thread_dispatcher = DispatcherSetting::Owned {
+            thread_count: Some(thread_count),
+        };
+        self
+    }
+
+    /// Sets the minimum block size of the worker pools of the thread dispatcher created for the simulation.
+    /// Must be a power of two. Ignored if a dispatcher is given.
+    pub fn worker_block_size(mut self, worker_block_size: i32) -> Self {
+        self.worker_block_size = worker_block_size;
+        self
+    }
+
+    /// Steps on the given thread dispatcher instead of creating one for the simulation, which takes ownership of it.
+    ///
+    /// A dispatcher runs one job at a time, so it can't be shared between simulations. To step several simulations on
+    /// one dispatcher, build them [single threaded](Self::single_threaded) and pass it to [`Simulation::step`].
+    pub fn thread_dispatcher(mut self, thread_dispatcher: ThreadDispatcher) -> Self {
+        self.thread_dispatcher = DispatcherSetting::Given(thread_dispatcher);
+        self
+    }
+
+    /// Steps on the calling thread instead of a thread dispatcher.
+    pub fn single_threaded(mut self) -> Self {
+        self.thread_dispatcher = DispatcherSetting::SingleThreaded;
+        self
+    }
+
+    /// Sets the handler for narrow phase events.
+    pub fn narrow_phase<H2: NarrowPhaseHandler>(
+        self,
+        narrow_phase: H2,
+    ) -> SimulationBuilder<H2, P> {
+        SimulationBuilder {
+            pool: self.pool,
+            pool_block_size: self.pool_block_size,
+            pool_expected_slot_count: self.pool_expected_slot_count,
+            thread_dispatcher: self.thread_dispatcher,
+            worker_block_size: self.worker_block_size,
+            narrow_phase,
+            pose_integrator: self.pose_integrator,
+            solve_description: self.solve_description,
+            iteration_schedule: self.iteration_schedule,
+            allocation_sizes: self.allocation_sizes,
+        }
+    }
+
+    /// Sets the integrator for body velocities.
+    pub fn pose_integrator<P2: PoseIntegrator>(
+        self,
+        pose_integrator: P2,
+    ) -> SimulationBuilder<H, P2> {
+        SimulationBuilder {
+            pool: self.pool,
+            pool_block_size: self.pool_block_size,
+            pool_expected_slot_count: self.pool_expected_slot_count,
+            thread_dispatcher: self.thread_dispatcher,
+            worker_block_size: self.worker_block_size,
+            narrow_phase: self.narrow_phase,
+            pose_integrator,
+            solve_description: self.solve_description,
+            iteration_schedule: self.iteration_schedule,
+            allocation_sizes: self.allocation_sizes,
+        }
+    }
+
+    /// Sets the solver settings.
+    ///
+    /// The description's velocity iteration scheduler must be left empty; use [`SimulationBuilder::iteration_schedule`] instead.
+    pub fn solve_description(mut self, solve_description: SolveDescription) -> Self {
+        self.solve_description = solve_description;
+        self
+    }
+
+    /// Sets the number of substeps executed each time the solver runs.
+    pub fn substeps(mut self, substep_count: i32) -> Self {
+        self.solve_description.substep_count = substep_count;
+        self
+    }
+
+    /// Sets the number of velocity iterations executed in each substep.
+    pub fn velocity_iterations(mut self, velocity_iteration_count: i32) -> Self {
+        self.solve_description.velocity_iteration_count = velocity_iteration_count;
+        self
+    }
+
+    /// Sets how many velocity iterations run in each substep, overriding [`SimulationBuilder::velocity_iterations`]
+    /// for the substeps the schedule covers.
+    pub fn iteration_schedule(mut self, iteration_schedule: IterationSchedule) -> Self {
+        self.iteration_schedule = Some(iteration_schedule);
+        self
+    }
+
+    /// Sets the number of bodies, statics and constraints to allocate space for up front.
+    pub fn capacity(mut self, bodies: i32, statics: i32, constraints: i32) -> Self {
+        self.allocation_sizes.bodies = bodies;
+        self.allocation_sizes.statics = statics;
+        self.allocation_sizes.constraints = constraints;
+        self
+    }
+
+    /// Sets the initial allocation sizes of the simulation.
+    pub fn allocation_sizes(mut self, allocation_sizes: SimulationAllocationSizes) -> Self {
+        self.allocation_sizes = allocation_sizes;
+        self
+    }
+
+    /// Validates the settings and creates the simulation.
+    ///
+    /// returns: The simulation, a [`ConfigError`] wrapped in [`Error::Config`] for invalid settings,
+    /// or the error Bepu raised while creating the simulation or its resources. A native library built differently from
+    /// this crate is an [`Error::SimdWidthMismatch`] or [`Error::NativeValidationDisabled`].
+    pub fn build(mut self) -> Result<Simulation, Error> {
+        self.fold_constant_schedule();
+        self.validate()?;
+        let pool = match self.pool {
+            Some(pool) => pool,
+            None => Arc::new(BufferPool::try_new(
+                self.pool_block_size,
+                self.pool_expected_slot_count,
+            )?),
+        };
+        let thread_dispatcher = match self.thread_dispatcher {
+            DispatcherSetting::Owned { thread_count } => {
+                let thread_count = thread_count.unwrap_or_else(|| {
+                    // SAFETY:
+                    //
+                    // Querying the platform has no preconditions.
+                    unsafe { crate::ffi::utils::get_platform_thread_count() }
+                });
+                Some(ThreadDispatcher::try_new(
+                    thread_count,
+                    self.worker_block_size,
+                )?)
+            }
+            DispatcherSetting::Given(thread_dispatcher) => Some(thread_dispatcher),
+            DispatcherSetting::SingleThreaded => None,
+        };
+        let mut simulation = Simulation::create(
+            pool,
+            self.narrow_phase,
+            self.pose_integrator,
+            self.solve_description,
+            self.allocation_sizes,
+            self.iteration_schedule,
+        )?;
+        simulation.set_thread_dispatcher(thread_dispatcher);
+        Ok(simulation)
+    }
+
+    fn fold_constant_schedule(&mut self) {
+        if let Some(IterationSchedule::Constant(iterations)) = self.iteration_schedule {
+            // A constant schedule is just the solver's own iteration count; no need to call back for it.
+            self.solve_description.velocity_iteration_count = iterations;
+            self.iteration_schedule = None;
+        }
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        let solve = &self.solve_description;
+        if solve.velocity_iteration_scheduler.is_some() {
+            return Err(ConfigError::RawScheduler);
+        }
+        if solve.substep_count < 1 {
+            return Err(ConfigError::SubstepCount(solve.substep_count));
+        }
+        if solve.velocity_iteration_count < 1 {
+            return Err(ConfigError::VelocityIterationCount(
+                solve.velocity_iteration_count,
+            ));
+        }
+        if solve.fallback_batch_threshold < 1 {
+            return Err(ConfigError::FallbackBatchThreshold(
+                solve.fallback_batch_threshold,
+            ));
+        }
+        let sizes = &self.allocation_sizes;
+        for (field, value) in [
+            ("bodies", sizes.bodies),
+            ("statics", sizes.statics),
+            ("islands", sizes.islands),
+            ("shapes_per_type", sizes.shapes_per_type),
+            ("constraints", sizes.constraints),
+            (
+                "constraints_per_type_batch",
+                sizes.constraints_per_type_batch,
+            ),
+            (
+                "constraint_count_per_body_estimate",
+                sizes.constraint_count_per_body_estimate,
+            ),
+        ] {
+            if value < 0 {
+                return Err(ConfigError::AllocationSize { field, value });
+            }
+        }
+        if self.pool.is_none() {
+            check_block_size(self.pool_block_size)?;
+            if self.pool_expected_slot_count < 1 {
+                return Err(ConfigError::ExpectedSlotCount(
+                    self.pool_expected_slot_count,
+                ));
+            }
+        }
+        if let DispatcherSetting::Owned { thread_count } = self.thread_dispatcher {
+            check_block_size(self.worker_block_size)?;
+            if let Some(thread_count) = thread_count.filter(|&count| count < 1) {
+                return Err(ConfigError::ThreadCount(thread_count));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<H: NarrowPhaseHandler> SimulationBuilder<H, DefaultPoseIntegrator> {
+    /// Sets the gravity applied to all dynamic bodies by the default pose integrator.
+    pub fn gravity(mut self, gravity: Vector3) -> Self {
+        self.pose_integrator.gravity = gravity;
+        self
+    }
+}
+
+fn check_block_size(block_size: i32) -> Result<(), ConfigError> {
+    if block_size > 0 && (block_size as u32).is_power_of_two() {
+        Ok(())
+    } else {
+        Err(ConfigError::BlockSize(block_size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    unsafe extern "C" fn scheduler(_context: *mut (), _substep_index: i32) -> i32 {
+        1
+    }
+
+    #[test]
+    fn default_settings_are_valid() {
+        assert_eq!(SimulationBuilder::new().validate(), Ok(()));
+        assert_eq!(
+            SimulationBuilder::new().single_threaded().validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn rejects_raw_scheduler() {
+        let solve_description = SolveDescription {
+            velocity_iteration_scheduler: Some(scheduler),
+            ..Default::default()
+        };
+        let builder = SimulationBuilder::new().solve_description(solve_description);
+        assert_eq!(builder.validate(), Err(ConfigError::RawScheduler));
+    }
+
+    #[test]
+    fn rejects_solver_counts_below_one() {
+        assert_eq!(
+            SimulationBuilder::new().substeps(0).validate(),
+            Err(ConfigError::SubstepCount(0))
+        );
+        assert_eq!(
+            SimulationBuilder::new().velocity_iterations(-1).validate(),
+            Err(ConfigError::VelocityIterationCount(-1))
+        );
+        let builder = SimulationBuilder::new().solve_description(SolveDescription::new(8, 1, 0));
+        assert_eq!(
+            builder.validate(),
+            Err(ConfigError::FallbackBatchThreshold(0))
+        );
+    }
+
+    #[test]
+    fn rejects_negative_allocation_sizes() {
+        let allocation_sizes = SimulationAllocationSizes {
+            constraint_count_per_body_estimate: -4,
+            ..Default::default()
+        };
+        let builder = SimulationBuilder::new().allocation_sizes(allocation_sizes);
+        assert_eq!(
+            builder.validate(),
+            Err(ConfigError::AllocationSize {
+                field: "constraint_count_per_body_estimate",
+                value: -4,
+            })
+        );
+        assert_eq!(
+            SimulationBuilder::new().capacity(-1, 0, 0).validate(),
+            Err(ConfigError::AllocationSize {
+                field: "bodies",
+                value: -1,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_pool_settings() {
+        assert_eq!(
+            SimulationBuilder::new().pool_settings(1000, 16).validate(),
+            Err(ConfigError::BlockSize(1000))
+        );
+        assert_eq!(
+            SimulationBuilder::new().pool_settings(1024, 0).validate(),
+            Err(ConfigError::ExpectedSlotCount(0))
+        );
+    }
+
+    #[test]
+    fn rejects_dispatcher_settings() {
+        assert_eq!(
+            SimulationBuilder::new().worker_block_size(0).validate(),
+            Err(ConfigError::BlockSize(0))
+        );
+        assert_eq!(
+            SimulationBuilder::new().thread_count(0).validate(),
+            Err(ConfigError::ThreadCount(0))
+        );
+        // Worker settings only matter for a dispatcher the builder creates.
+        let builder = SimulationBuilder::new()
+            .worker_block_size(0)
+            .single_threaded();
+        assert_eq!(builder.validate(), Ok(()));
+    }
+
+    #[test]
+    fn block_sizes_must_be_positive_powers_of_two() {
+        for block_size in [1, 2, 1024, 1 << 30] {
+            assert_eq!(check_block_size(block_size), Ok(()));
+        }
+        for block_size in [0, -1, 3, 1000, i32::MIN] {
+            assert_eq!(
+                check_block_size(block_size),
+                Err(ConfigError::BlockSize(block_size))
+            );
+        }
+    }
+
+    #[test]
+    fn constant_schedule_folds_into_iteration_count() {
+        let mut builder =
+            SimulationBuilder::new().iteration_schedule(IterationSchedule::Constant(3));
+        builder.fold_constant_schedule();
+        assert!(builder.iteration_schedule.is_none());
+        assert_eq!(builder.solve_description.velocity_iteration_count, 3);
+
+        let mut builder =
+            SimulationBuilder::new().iteration_schedule(IterationSchedule::Constant(0));
+        builder.fold_constant_schedule();
+        assert_eq!(
+            builder.validate(),
+            Err(ConfigError::VelocityIterationCount(0))
+        );
+
+        let mut builder =
+            SimulationBuilder::new().iteration_schedule(IterationSchedule::PerSubstep(vec![2, 1]));
+        builder.fold_constant_schedule();
+        assert!(matches!(
+            builder.iteration_schedule,
+            Some(IterationSchedule::PerSubstep(_))
+        ));
+        assert_eq!(builder.solve_description.velocity_iteration_count, 8);
+    }
+}
@@ -0,0 +1,407 @@
+use std::simd::{Mask, Simd};
+
+use crate::types::{
+    callbacks::{
+        narrow_phase::NarrowPhaseCallbacks,
+        pose::{AngularIntegrationMode, PoseIntegratorCallbacks},
+    },
+    collisions::{
+        CollidableMobility, CollidablePair, CollidableReference, ConvexContactManifold,
+        NonconvexContactManifold, PairMaterialProperties,
+    },
+    constraints::springs::SpringSettings,
+    handles::SimulationHandle,
+    math::{
+        scalar::Vector3,
+        simd::{BodyInertiaWide, BodyVelocityWide, QuaternionWide, Vector3Wide},
+    },
+    WIDEST_LANE,
+};
+
+/// A contact manifold being configured by a [`NarrowPhaseHandler`].
+pub enum ContactManifold<'a> {
+    /// Manifold of a pair whose contacts all share one surface normal.
+    Convex(&'a mut ConvexContactManifold),
+    /// Manifold of a pair involving a compound or mesh, where each contact has its own normal.
+    Nonconvex(&'a mut NonconvexContactManifold),
+}
+
+/// Rust-side narrow phase callbacks owned by a [`super::Simulation`].
+///
+/// The handler is called from every worker of the thread dispatcher used to step the simulation, so it must be `Send + Sync`;
+/// use `worker_index` to index per-worker state if needed.
+///
+/// Every method has a default matching BepuPhysics' demo callbacks, so implementors only need to override what they care about.
+pub trait NarrowPhaseHandler: Send + Sync + 'static {
+    /// Called for each pair of collidables with overlapping bounding boxes found by the broad phase.
+    ///
+    /// # Arguments
+    ///
+    /// * `worker_index`: Index of the worker within the thread dispatcher that's running this callback.
+    /// * `a`: First collidable in the pair.
+    /// * `b`: Second collidable in the pair.
+    /// * `speculative_margin`: Speculative contact margin for the pair. Calculated ahead of time, but can be overridden.
+    ///
+    /// returns: True if the collision detection should run for this pair, false otherwise.
+    fn allow_contact_generation(
+        &self,
+        _worker_index: i32,
+        a: CollidableReference,
+        b: CollidableReference,
+        _speculative_margin: &mut f32,
+    ) -> bool {
+        // Only dynamic bodies respond to contacts, so pairs without one would generate constraints that do nothing.
+        a.mobility() == CollidableMobility::Dynamic || b.mobility() == CollidableMobility::Dynamic
+    }
+
+    /// For pairs involving compound collidables, this is invoked for each pair of children with overlapping bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `worker_index`: Index of the worker within the thread dispatcher that's running this callback.
+    /// * `pair`: References to the parent collidables in this pair.
+    /// * `child_index_a`: Index of the child belonging to the first collidable in the pair.
+    /// * `child_index_b`: Index of the child belonging to the second collidable in the pair.
+    ///
+    /// returns: True if the collision detection should run for these children, false otherwise.
+    fn allow_contact_generation_between_children(
+        &self,
+        _worker_index: i32,
+        _pair: CollidablePair,
+        _child_index_a: i32,
+        _child_index_b: i32,
+    ) -> bool {
+        true
+    }
+
+    /// Called after contacts have been found for a collidable pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `worker_index`: Index of the worker within the thread dispatcher that's running this callback.
+    /// * `pair`: References to the parent collidables in this pair.
+    /// * `manifold`: Contacts identified between the pair.
+    /// * `material`: Contact constraint material properties to use for the constraint. Starts out uninitialized and must be written if this returns true.
+    ///
+    /// returns: True if a contact constraint should be created for this contact manifold, false otherwise.
+    fn configure_contact_manifold(
+        &self,
+        _worker_index: i32,
+        _pair: CollidablePair,
+        _manifold: ContactManifold<'_>,
+        material: &mut PairMaterialProperties,
+    ) -> bool {
+        *material = PairMaterialProperties::new(1.0, 2.0, SpringSettings::new(30.0, 1.0));
+        true
+    }
+
+    /// Called for contacts identified between children in a compound-involving pair prior to being processed into the top level contact manifold.
+    ///
+    /// # Arguments
+    ///
+    /// * `worker_index`: Index of the worker within the thread dispatcher that's running this callback.
+    /// * `pair`: References to the parent collidables in this pair.
+    /// * `child_index_a`: Index of the child belonging to the first collidable in the pair.
+    /// * `child_index_b`: Index of the child belonging to the second collidable in the pair.
+    /// * `manifold`: Contacts identified between the pair.
+    ///
+    /// returns: True if the contacts in this child pair should be considered for constraint generation, false otherwise.
+    fn configure_child_contact_manifold(
+        &self,
+        _worker_index: i32,
+        _pair: CollidablePair,
+        _child_index_a: i32,
+        _child_index_b: i32,
+        _manifold: &mut ConvexContactManifold,
+    ) -> bool {
+        true
+    }
+}
+
+/// Narrow phase handler that creates contacts for every pair involving a dynamic body, using fixed default materials.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultNarrowPhaseHandler;
+
+impl NarrowPhaseHandler for DefaultNarrowPhaseHandler {}
+
+/// Builds the raw callbacks that forward to a handler.
+///
+/// # Safety
+///
+/// The handler must outlive the simulation the callbacks are given to and must not move while it is alive.
+pub(crate) unsafe fn narrow_phase_callbacks<H: NarrowPhaseHandler>(
+    handler: *const H,
+) -> NarrowPhaseCallbacks {
+    NarrowPhaseCallbacks {
+        initialize_function: None,
+        dispose_function: None,
+        allow_contact_generation_function: Some(allow_contact_generation::<H>),
+        allow_contact_generation_between_children_function: Some(
+            allow_contact_generation_between_children::<H>,
+        ),
+        configure_convex_contact_manifold_function: Some(configure_convex_contact_manifold::<H>),
+        configure_nonconvex_contact_manifold_function: Some(
+            configure_nonconvex_contact_manifold::<H>,
+        ),
+        configure_child_contact_manifold_function: Some(configure_child_contact_manifold::<H>),
+        context: handler as *mut (),
+    }
+}
+
+// The trampolines below are only installed by `narrow_phase_callbacks`, so the context always points to a live `H`.
+// Every pointer argument refers to native stack or pinned memory that stays valid for the duration of the call.
+
+unsafe extern "C" fn allow_contact_generation<H: NarrowPhaseHandler>(
+    _simulation_handle: SimulationHandle,
+    worker_index: i32,
+    a: CollidableReference,
+    b: CollidableReference,
+    speculative_margin: *mut f32,
+    context: *mut (),
+) -> bool {
+    (*(context as *const H)).allow_contact_generation(worker_index, a, b, &mut *speculative_margin)
+}
+
+unsafe extern "C" fn allow_contact_generation_between_children<H: NarrowPhaseHandler>(
+    _simulation_handle: SimulationHandle,
+    worker_index: i32,
+    pair: CollidablePair,
+    child_index_a: i32,
+    child_index_b: i32,
+    context: *mut (),
+) -> bool {
+    (*(context as *const H)).allow_contact_generation_between_children(
+        worker_index,
+        pair,
+        child_index_a,
+        child_index_b,
+    )
+}
+
+unsafe extern "C" fn configure_convex_contact_manifold<H: NarrowPhaseHandler>(
+    _simulation_handle: SimulationHandle,
+    worker_index: i32,
+    pair: CollidablePair,
+    manifold: *mut ConvexContactManifold,
+    material: *mut PairMaterialProperties,
+    context: *mut (),
+) -> bool {
+    (*(context as *const H)).configure_contact_manifold(
+        worker_index,
+        pair,
+        ContactManifold::Convex(&mut *manifold),
+        &mut *material,
+    )
+}
+
+unsafe extern "C" fn configure_nonconvex_contact_manifold<H: NarrowPhaseHandler>(
+    _simulation_handle: SimulationHandle,
+    worker_index: i32,
+    pair: CollidablePair,
+    manifold: *mut NonconvexContactManifold,
+    material: *mut PairMaterialProperties,
+    context: *mut (),
+) -> bool {
+    (*(context as *const H)).configure_contact_manifold(
+        worker_index,
+        pair,
+        ContactManifold::Nonconvex(&mut *manifold),
+        &mut *material,
+    )
+}
+
+unsafe extern "C" fn configure_child_contact_manifold<H: NarrowPhaseHandler>(
+    _simulation_handle: SimulationHandle,
+    worker_index: i32,
+    pair: CollidablePair,
+    child_index_a: i32,
+    child_index_b: i32,
+    manifold: *mut ConvexContactManifold,
+    context: *mut (),
+) -> bool {
+    (*(context as *const H)).configure_child_contact_manifold(
+        worker_index,
+        pair,
+        child_index_a,
+        child_index_b,
+        &mut *manifold,
+    )
+}
+
+/// State of a bundle of bodies being integrated, handed to [`PoseIntegrator::integrate`].
+///
+/// Each lane of the wide values corresponds to one body in the bundle.
+pub struct IntegrationContext<'a> {
+    /// Indices of the bodies in the active body set. These are distinct from body handles and can change over time.
+    pub body_indices: &'a Simd<i32, WIDEST_LANE>,
+    /// Current positions of the bodies.
+    pub position: &'a Vector3Wide,
+    /// Current orientations of the bodies.
+    pub orientation: &'a QuaternionWide,
+    /// Inertia properties of the bodies in their local space.
+    pub local_inertia: &'a BodyInertiaWide,
+    /// Lanes holding a body that should be integrated. Writes to other lanes are ignored.
+    pub integration_mask: &'a Mask<i32, WIDEST_LANE>,
+    /// Index of the worker within the thread dispatcher that's running this callback.
+    pub worker_index: i32,
+    /// Duration of the integration step for each lane.
+    pub dt: Simd<f32, WIDEST_LANE>,
+}
+
+/// Rust-side pose integrator owned by a [`super::Simulation`].
+///
+/// The integration settings are read once when the simulation is created.
+pub trait PoseIntegrator: Send + Sync + 'static {
+    /// How the pose integrator should handle angular velocity integration.
+    fn angular_integration_mode(&self) -> AngularIntegrationMode {
+        AngularIntegrationMode::Nonconserving
+    }
+
+    /// Whether unconstrained bodies are integrated with the same number of substeps as constrained bodies.
+    /// If false, unconstrained bodies use a single step of length equal to the timestep.
+    fn allow_substeps_for_unconstrained_bodies(&self) -> bool {
+        false
+    }
+
+    /// Whether [`PoseIntegrator::integrate`] is called for bundles including kinematic bodies.
+    /// Most use cases should leave this false.
+    fn integrate_velocity_for_kinematics(&self) -> bool {
+        false
+    }
+
+    /// Called before each simulation stage which could execute velocity integration, on the thread stepping the simulation.
+    ///
+    /// Use it to cache anything that depends only on the timestep.
+    ///
+    /// # Arguments
+    ///
+    /// * `dt`: Timestep duration that subsequent velocity integrations will be invoked with.
+    fn prepare_for_integration(&mut self, _dt: f32) {}
+
+    /// Integrates the velocity of a bundle of bodies. Called concurrently from every worker of the thread dispatcher.
+    ///
+    /// # Arguments
+    ///
+    /// * `context`: State of the bodies being integrated.
+    /// * `velocity`: Velocities of the bodies, to be updated in place.
+    fn integrate(&self, context: IntegrationContext<'_>, velocity: &mut BodyVelocityWide);
+}
+
+/// Pose integrator applying constant gravity and exponential damping, matching the BepuPhysics demos.
+pub struct DefaultPoseIntegrator {
+    /// Gravity applied to all dynamic bodies.
+    pub gravity: Vector3,
+    /// Fraction of linear velocity to remove per unit of time. Values range from 0 to 1.
+    pub linear_damping: f32,
+    /// Fraction of angular velocity to remove per unit of time. Values range from 0 to 1.
+    pub angular_damping: f32,
+    // Per-timestep values cached by `prepare_for_integration`, since integration only ever sees the prepared dt.
+    gravity_dt: [Simd<f32, WIDEST_LANE>; 3],
+    linear_damping_dt: Simd<f32, WIDEST_LANE>,
+    angular_damping_dt: Simd<f32, WIDEST_LANE>,
+}
+
+impl DefaultPoseIntegrator {
+    /// Constructs a new default pose integrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `gravity`: Gravity applied to all dynamic bodies.
+    /// * `linear_damping`: Fraction of linear velocity to remove per unit of time. Values range from 0 to 1.
+    /// * `angular_damping`: Fraction of angular velocity to remove per unit of time. Values range from 0 to 1.
+    pub fn new(gravity: Vector3, linear_damping: f32, angular_damping: f32) -> Self {
+        Self {
+            gravity,
+            linear_damping,
+            angular_damping,
+            gravity_dt: [Simd::splat(0.0); 3],
+            linear_damping_dt: Simd::splat(1.0),
+            angular_damping_dt: Simd::splat(1.0),
+        }
+    }
+}
+
+impl Default for DefaultPoseIntegrator {
+    fn default() -> Self {
+        Self::new(Vector3::new(0.0, -10.0, 0.0), 0.03, 0.03)
+    }
+}
+
+impl PoseIntegrator for DefaultPoseIntegrator {
+    fn prepare_for_integration(&mut self, dt: f32) {
+        // Damping is expressed per unit of time, so it is raised to dt to stay independent of the timestep.
+        self.linear_damping_dt = Simd::splat((1.0 - self.linear_damping).clamp(0.0, 1.0).powf(dt));
+        self.angular_damping_dt =
+            Simd::splat((1.0 - self.angular_damping).clamp(0.0, 1.0).powf(dt));
+        self.gravity_dt = [
+            Simd::splat(self.gravity.x * dt),
+            Simd::splat(self.gravity.y * dt),
+            Simd::splat(self.gravity.z * dt),
+        ];
+    }
+
+    fn integrate(&self, _context: IntegrationContext<'_>, velocity: &mut BodyVelocityWide) {
+        let linear = &mut velocity.linear;
+        linear.x = (linear.x + self.gravity_dt[0]) * self.linear_damping_dt;
+        linear.y = (linear.y + self.gravity_dt[1]) * self.linear_damping_dt;
+        linear.z = (linear.z + self.gravity_dt[2]) * self.linear_damping_dt;
+        let angular = &mut velocity.angular;
+        angular.x *= self.angular_damping_dt;
+        angular.y *= self.angular_damping_dt;
+        angular.z *= self.angular_damping_dt;
+    }
+}
+
+/// Builds the raw callbacks that forward to a pose integrator.
+///
+/// The integrator pointer must be passed as the callback data when creating the simulation.
+pub(crate) fn pose_integrator_callbacks<P: PoseIntegrator>(
+    integrator: &P,
+) -> PoseIntegratorCallbacks {
+    PoseIntegratorCallbacks {
+        angular_integration_mode: integrator.angular_integration_mode(),
+        allow_substeps_for_unconstrained_bodies: integrator
+            .allow_substeps_for_unconstrained_bodies(),
+        integrate_velocity_for_kinematics: integrator.integrate_velocity_for_kinematics(),
+        use_scalar_callback: false,
+        initialize: None,
+        prepare_for_integration: Some(prepare_for_integration::<P>),
+        integrate_velocity: integrate_velocity::<P>,
+    }
+}
+
+// Like the narrow phase trampolines, these are only installed with a data pointer to a live `P`.
+// `prepare_for_integration` runs on the stepping thread while no integration is in flight, so the unique borrow can't alias.
+
+unsafe extern "C" fn prepare_for_integration<P: PoseIntegrator>(
+    _simulation: SimulationHandle,
+    dt: f32,
+    data: *mut (),
+) {
+    (*(data as *mut P)).prepare_for_integration(dt)
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn integrate_velocity<P: PoseIntegrator>(
+    _simulation: SimulationHandle,
+    body_indices: *const Simd<i32, WIDEST_LANE>,
+    position: *mut Vector3Wide,
+    orientation: *mut QuaternionWide,
+    local_inertia: *mut BodyInertiaWide,
+    mask: *const Mask<i32, WIDEST_LANE>,
+    worker_index: i32,
+    dt: *const Simd<f32, WIDEST_LANE>,
+    velocity: *mut BodyVelocityWide,
+    data: *mut (),
+) {
+    let context = IntegrationContext {
+        body_indices: &*body_indices,
+        position: &*position,
+        orientation: &*orientation,
+        local_inertia: &*local_inertia,
+        integration_mask: &*mask,
+        worker_index,
+        dt: *dt,
+    };
+    (*(data as *const P)).integrate(context, &mut *velocity)
+}
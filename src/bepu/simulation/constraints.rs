@@ -0,0 +1,281 @@
+use crate::{
+    types::{
+        constraints::{joints::*, limits::*, motors::*, servos::*},
+        handles::{BodyHandle, ConstraintHandle, SimulationHandle},
+    },
+    Error,
+};
+
+use super::Simulation;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A constraint description that can be added to a simulation's solver.
+///
+/// Implemented for every constraint type in [`crate::types::constraints`]. The trait is sealed,
+/// since the simulation trusts its body count and entrypoints to match the native constraint layout.
+pub trait ConstraintDescription: sealed::Sealed + Sized {
+    /// Number of bodies connected by constraints of this type.
+    const BODY_COUNT: usize;
+
+    #[doc(hidden)]
+    unsafe fn add(
+        simulation: SimulationHandle,
+        bodies: &[BodyHandle],
+        description: Self,
+    ) -> ConstraintHandle;
+
+    #[doc(hidden)]
+    unsafe fn apply(
+        simulation: SimulationHandle,
+        handle: ConstraintHandle,
+        description: Self,
+    ) -> bool;
+}
+
+macro_rules! impl_constraint_description {
+    ($description:ty, $body_count:literal, $add:ident, $apply:ident) => {
+        impl sealed::Sealed for $description {}
+
+        impl ConstraintDescription for $description {
+            const BODY_COUNT: usize = $body_count;
+
+            unsafe fn add(
+                simulation: SimulationHandle,
+                bodies: &[BodyHandle],
+                description: Self,
+            ) -> ConstraintHandle {
+                crate::ffi::constraints::$add(
+                    simulation,
+                    bodies.as_ptr(),
+                    bodies.len() as i32,
+                    description,
+                )
+            }
+
+            unsafe fn apply(
+                simulation: SimulationHandle,
+                handle: ConstraintHandle,
+                description: Self,
+            ) -> bool {
+                crate::ffi::constraints::$apply(simulation, handle, description)
+            }
+        }
+    };
+}
+
+impl_constraint_description!(
+    BallSocket,
+    2,
+    add_ball_socket,
+    apply_ball_socket_description
+);
+impl_constraint_description!(Hinge, 2, add_hinge, apply_hinge_description);
+impl_constraint_description!(Weld, 2, add_weld, apply_weld_description);
+impl_constraint_description!(
+    SwivelHinge,
+    2,
+    add_swivel_hinge,
+    apply_swivel_hinge_description
+);
+impl_constraint_description!(
+    AngularHinge,
+    2,
+    add_angular_hinge,
+    apply_angular_hinge_description
+);
+impl_constraint_description!(
+    PointOnLineServo,
+    2,
+    add_point_on_line_servo,
+    apply_point_on_line_servo_description
+);
+impl_constraint_description!(
+    CenterDistanceConstraint,
+    2,
+    add_center_distance,
+    apply_center_distance_description
+);
+impl_constraint_description!(
+    AngularMotor,
+    2,
+    add_angular_motor,
+    apply_angular_motor_description
+);
+impl_constraint_description!(
+    LinearAxisMotor,
+    2,
+    add_linear_axis_motor,
+    apply_linear_axis_motor_description
+);
+impl_constraint_description!(
+    TwistMotor,
+    2,
+    add_twist_motor,
+    apply_twist_motor_description
+);
+impl_constraint_description!(
+    AngularServo,
+    2,
+    add_angular_servo,
+    apply_angular_servo_description
+);
+impl_constraint_description!(
+    LinearAxisServo,
+    2,
+    add_linear_axis_servo,
+    apply_linear_axis_servo_description
+);
+impl_constraint_description!(
+    OneBodyLinearServo,
+    1,
+    add_one_body_linear_servo,
+    apply_one_body_linear_servo_description
+);
+impl_constraint_description!(
+    OneBodyAngularServo,
+    1,
+    add_one_body_angular_servo,
+    apply_one_body_angular_servo_description
+);
+impl_constraint_description!(
+    TwistServo,
+    2,
+    add_twist_servo,
+    apply_twist_servo_description
+);
+impl_constraint_description!(
+    TwistLimit,
+    2,
+    add_twist_limit,
+    apply_twist_limit_description
+);
+impl_constraint_description!(
+    SwingLimit,
+    2,
+    add_swing_limit,
+    apply_swing_limit_description
+);
+impl_constraint_description!(
+    DistanceLimit,
+    2,
+    add_distance_limit,
+    apply_distance_limit_description
+);
+impl_constraint_description!(
+    LinearAxisLimit,
+    2,
+    add_linear_axis_limit,
+    apply_linear_axis_limit_description
+);
+
+impl Simulation {
+    /// Adds a constraint between bodies to the simulation, returning a handle that can be used to modify or remove it later.
+    ///
+    /// returns: The handle of the constraint, or the error Bepu raised while adding it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bodies doesn't match the constraint type,
+    /// if any handle does not refer to a body in this simulation, or if the same body is listed more than once.
+    pub fn add_constraint<D: ConstraintDescription>(
+        &mut self,
+        bodies: &[BodyHandle],
+        description: D,
+    ) -> Result<ConstraintHandle, Error> {
+        assert_eq!(
+            bodies.len(),
+            D::BODY_COUNT,
+            "constraint type connects a different number of bodies"
+        );
+        for (i, body) in bodies.iter().enumerate() {
+            assert!(
+                self.contains_body(*body),
+                "body handle does not exist in this simulation"
+            );
+            assert!(
+                !bodies[..i].contains(body),
+                "constraints cannot refer to the same body more than once"
+            );
+        }
+        let _pool = self.pool.lock();
+        // SAFETY:
+        //
+        // Every body handle was checked against the simulation's handle mapping above,
+        // and the body slice is only read for the duration of the call.
+        let handle = unsafe { D::add(self.handle, bodies, description) };
+        if handle.value < 0 {
+            return Err(Error::take_native());
+        }
+        Ok(handle)
+    }
+
+    /// Removes a constraint from the simulation.
+    ///
+    /// returns: The error Bepu raised while removing the constraint, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle does not refer to a constraint in this simulation.
+    pub fn remove_constraint(&mut self, handle: ConstraintHandle) -> Result<(), Error> {
+        assert!(
+            self.contains_constraint(handle),
+            "constraint handle does not exist in this simulation"
+        );
+        let _pool = self.pool.lock();
+        // SAFETY:
+        //
+        // The handle was checked against the solver's handle mapping above.
+        let removed = unsafe { crate::ffi::constraints::remove_constraint(self.handle, handle) };
+        if removed {
+            Ok(())
+        } else {
+            Err(Error::take_native())
+        }
+    }
+
+    /// Gets whether the handle refers to a constraint that currently exists in the simulation.
+    pub fn contains_constraint(&self, handle: ConstraintHandle) -> bool {
+        let mut exists = false;
+        // SAFETY:
+        //
+        // The solver checks the handle against its own bounds before reading the mapping.
+        let checked =
+            unsafe { crate::ffi::constraints::constraint_exists(self.handle, handle, &mut exists) };
+        if !checked {
+            Error::panic_native("checking whether a constraint exists");
+        }
+        exists
+    }
+
+    /// Applies a description to an existing constraint, replacing its current properties.
+    ///
+    /// returns: The error Bepu raised while applying the description, if any.
+    /// A constraint of a different type than the description is an [`Error::InvalidHandle`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle does not refer to a constraint in this simulation.
+    pub fn apply_description<D: ConstraintDescription>(
+        &mut self,
+        handle: ConstraintHandle,
+        description: D,
+    ) -> Result<(), Error> {
+        assert!(
+            self.contains_constraint(handle),
+            "constraint handle does not exist in this simulation"
+        );
+        // SAFETY:
+        //
+        // The handle was checked against the solver's handle mapping above, and the native side
+        // refuses descriptions whose type doesn't match the constraint.
+        let applied = unsafe { D::apply(self.handle, handle, description) };
+        if applied {
+            Ok(())
+        } else {
+            Err(Error::take_native())
+        }
+    }
+}
@@ -1,12 +1,31 @@
-use crate::types::{
-    callbacks::{narrow_phase::NarrowPhaseCallbacks, pose::PoseIntegratorCallbacks},
-    handles::{BufferPoolHandle, SimulationHandle},
-    simulation::{SimulationAllocationSizes, SolveDescription},
+use std::sync::Arc;
+
+use crate::{
+    types::{
+        handles::{InstanceHandle, SimulationHandle},
+        simulation::{SimulationAllocationSizes, SolveDescription},
+    },
+    Error,
 };
 
-use super::buffer_pool::BufferPool;
+use self::callbacks::{NarrowPhaseHandler, PoseIntegrator};
+
+use super::{buffer_pool::BufferPool, dispatcher::ThreadDispatcher};
+
+pub use self::{
+    builder::{ConfigError, SimulationBuilder},
+    schedule::IterationSchedule,
+};
 
+pub mod bodies;
+pub mod builder;
 pub mod callbacks;
+pub mod constraints;
+pub mod queries;
+pub mod schedule;
+pub mod shapes;
+pub mod statics;
+mod validation;
 
 /// This type holds a handle to a simulation owned by the C# code.
 ///
@@ -14,47 +33,208 @@ pub mod callbacks;
 /// when this struct is dropped the simulation is destroyed.
 ///
 /// In order to operate on the simulation data in C# land unique access is required through a mutable reference.
+///
+/// The simulation can be moved to another thread. A pool shared with other simulations is locked around every call
+/// that may allocate from it, and the simulation's own dispatcher is never shared.
 pub struct Simulation {
+    handle: SimulationHandle,
     /// The pool used for this simulation.
     ///
-    /// Does not represent ownership of the pool,
-    /// but ensures that when unique access is used given
-    /// it is the correct buffer pool.
-    pool: BufferPoolHandle,
-    handle: SimulationHandle,
+    /// Shared ownership keeps the pool alive for as long as the simulation points into it.
+    /// It is dropped after the simulation is destroyed in `Drop::drop`.
+    pool: Arc<BufferPool>,
+    /// Dispatcher the simulation steps on by default, if any.
+    ///
+    /// Owned solely by the simulation, so no other step can be dispatching on it. Dropped after the simulation is destroyed.
+    thread_dispatcher: Option<ThreadDispatcher>,
+    /// Handler the native narrow phase callbacks point into.
+    ///
+    /// Boxed so its address stays stable when the simulation moves. It is dropped after the simulation is destroyed in `Drop::drop`.
+    narrow_phase: Box<dyn NarrowPhaseHandler>,
+    /// Integrator the native pose integrator callbacks point into. Owned the same way as `narrow_phase`.
+    pose_integrator: Box<dyn PoseIntegrator>,
+    /// Schedule the native solver's velocity iteration scheduler points into, if any. Owned the same way as `narrow_phase`.
+    iteration_schedule: Option<Box<IterationSchedule>>,
 }
 
 impl Drop for Simulation {
     fn drop(&mut self) {
+        // Destroying the simulation returns its memory to the pool.
+        let _pool = self.pool.lock();
         // SAFETY:
         //
         // The ONLY use of ffi::simulation::create_simulation() is in this constructor, and this type holds the only reference to handles.
         // When this drop is called the handle will no longer be used.
-        unsafe {
-            crate::ffi::simulation::destroy_simulation(self.handle);
+        let destroyed = unsafe { crate::ffi::simulation::destroy_simulation(self.handle) };
+        if !destroyed && !std::thread::panicking() {
+            Error::panic_native("destroying a simulation");
         }
     }
 }
 
+// Engines move simulations onto worker threads, so losing `Send` would be a breaking change.
+const _: fn() = || {
+    fn assert_send<T: Send>() {}
+    assert_send::<Simulation>();
+};
+
 impl Simulation {
-    pub fn new(
-        pool: &BufferPool,
-        np_callback: NarrowPhaseCallbacks,
-        pose_callback: PoseIntegratorCallbacks,
+    /// Creates a builder for a simulation with validated settings and defaults for everything.
+    pub fn builder() -> SimulationBuilder {
+        SimulationBuilder::new()
+    }
+
+    /// Creates a simulation that allocates from `pool` and steps on the calling thread.
+    ///
+    /// [`SimulationBuilder`] creates the pool and a thread dispatcher for you.
+    ///
+    /// returns: The simulation, or the error Bepu raised while creating it. A native library built differently from this
+    /// crate is an [`Error::SimdWidthMismatch`] or [`Error::NativeValidationDisabled`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the solve description has a velocity iteration scheduler, since nothing would keep its context alive.
+    /// Use [`SimulationBuilder::iteration_schedule`] instead.
+    pub fn new<H: NarrowPhaseHandler, P: PoseIntegrator>(
+        pool: Arc<BufferPool>,
+        narrow_phase: H,
+        pose_integrator: P,
         solve_desc: SolveDescription,
         alloc_sizes: SimulationAllocationSizes,
-    ) -> Self {
-        Simulation {
-            handle: unsafe {
-                crate::ffi::simulation::create_simulation(
-                    pool.handle(),
-                    np_callback,
-                    pose_callback,
-                    solve_desc,
-                    alloc_sizes,
-                )
-            },
-            pool: pool.handle(),
+    ) -> Result<Self, Error> {
+        assert!(
+            solve_desc.velocity_iteration_scheduler.is_none(),
+            "velocity iteration schedulers must be set through `SimulationBuilder::iteration_schedule`"
+        );
+        Self::create(
+            pool,
+            narrow_phase,
+            pose_integrator,
+            solve_desc,
+            alloc_sizes,
+            None,
+        )
+    }
+
+    fn create<H: NarrowPhaseHandler, P: PoseIntegrator>(
+        pool: Arc<BufferPool>,
+        narrow_phase: H,
+        pose_integrator: P,
+        mut solve_desc: SolveDescription,
+        alloc_sizes: SimulationAllocationSizes,
+        iteration_schedule: Option<IterationSchedule>,
+    ) -> Result<Self, Error> {
+        validation::check_native_validation()?;
+        // SAFETY:
+        //
+        // Querying the vector width has no preconditions.
+        let simd_width = unsafe { crate::ffi::utils::get_simd_width() } as usize;
+        if simd_width != crate::types::WIDEST_LANE {
+            return Err(Error::SimdWidthMismatch {
+                native: simd_width,
+                expected: crate::types::WIDEST_LANE,
+            });
         }
+        let iteration_schedule = iteration_schedule.map(Box::new);
+        if let Some(schedule) = &iteration_schedule {
+            // SAFETY:
+            //
+            // The schedule is boxed and owned by the returned simulation, which destroys the native simulation before dropping it.
+            unsafe { schedule.install(&mut solve_desc) };
+        }
+        let narrow_phase = Box::new(narrow_phase);
+        let mut pose_integrator = Box::new(pose_integrator);
+        // SAFETY:
+        //
+        // The handler and integrator are boxed and owned by the returned simulation, which destroys the native simulation before dropping them.
+        let handle = unsafe {
+            let _pool = pool.lock();
+            crate::ffi::simulation::create_simulation(
+                pool.handle(),
+                callbacks::narrow_phase_callbacks(&*narrow_phase),
+                callbacks::pose_integrator_callbacks(&*pose_integrator),
+                &mut *pose_integrator as *mut P as *mut (),
+                solve_desc,
+                alloc_sizes,
+            )
+        };
+        if handle.is_null() {
+            return Err(Error::take_native());
+        }
+        Ok(Simulation {
+            handle,
+            pool,
+            thread_dispatcher: None,
+            narrow_phase,
+            pose_integrator,
+            iteration_schedule,
+        })
+    }
+
+    /// Steps the simulation forward a single time.
+    ///
+    /// # Arguments
+    ///
+    /// * `dt`: Duration of the timestep.
+    /// * `dispatcher`: Dispatcher to spread the work of the step over. With `None`, the simulation's own dispatcher is used
+    ///   if it has one, and the step runs on the calling thread otherwise.
+    ///
+    /// returns: The error Bepu raised during the step, if any.
+    ///
+    /// # Remarks
+    ///
+    /// The simulation's buffer pool is locked for the whole step, so callbacks must not allocate from it.
+    pub fn step(&mut self, dt: f32, dispatcher: Option<&ThreadDispatcher>) -> Result<(), Error> {
+        let dispatcher = dispatcher
+            .or(self.thread_dispatcher.as_ref())
+            .map_or(InstanceHandle::null(), |dispatcher| dispatcher.handle());
+        let _pool = self.pool.lock();
+        // SAFETY:
+        //
+        // The simulation is uniquely borrowed, so nothing else can touch the pose integrator the native callbacks point into
+        // while the step runs. The dispatcher is borrowed for the duration of the call, and it isn't `Sync`,
+        // so no other thread can be dispatching on it at the same time.
+        // The pool is locked above, so simulations sharing it can't allocate from it concurrently.
+        let completed = unsafe { crate::ffi::simulation::timestep(self.handle, dt, dispatcher) };
+        if completed {
+            Ok(())
+        } else {
+            Err(Error::take_native())
+        }
+    }
+
+    /// Gets the buffer pool the simulation allocates from.
+    pub fn pool(&self) -> &Arc<BufferPool> {
+        &self.pool
+    }
+
+    /// Gets the thread dispatcher the simulation steps on by default, if any.
+    pub fn thread_dispatcher(&self) -> Option<&ThreadDispatcher> {
+        self.thread_dispatcher.as_ref()
+    }
+
+    /// Sets the thread dispatcher the simulation steps on by default. With `None`, steps run on the calling thread.
+    ///
+    /// returns: The dispatcher the simulation stepped on before, if any.
+    pub fn set_thread_dispatcher(
+        &mut self,
+        thread_dispatcher: Option<ThreadDispatcher>,
+    ) -> Option<ThreadDispatcher> {
+        std::mem::replace(&mut self.thread_dispatcher, thread_dispatcher)
+    }
+
+    /// Gets the velocity iteration schedule the simulation was created with, if any.
+    pub fn iteration_schedule(&self) -> Option<&IterationSchedule> {
+        self.iteration_schedule.as_deref()
+    }
+
+    /// Gets the narrow phase handler the simulation was created with.
+    pub fn narrow_phase(&self) -> &dyn NarrowPhaseHandler {
+        &*self.narrow_phase
+    }
+
+    /// Gets the pose integrator the simulation was created with.
+    pub fn pose_integrator(&self) -> &dyn PoseIntegrator {
+        &*self.pose_integrator
     }
 }
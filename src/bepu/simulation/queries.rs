@@ -0,0 +1,421 @@
+use crate::{
+    types::{
+        body::BodyVelocity,
+        collisions::{CollidableMobility, CollidableReference},
+        math::scalar::{RigidPose, Vector3},
+        queries::{BroadPhaseOverlapHandler, RayHit, RayHitHandler, SweepHit, SweepHitHandler},
+        shapes::{Box, Capsule, Cylinder, Sphere, Triangle},
+    },
+    Error,
+};
+
+use super::{
+    shapes::{ConvexShape, Shape, ShapeHandle},
+    Simulation,
+};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A convex shape that can be swept through a simulation.
+///
+/// Implemented for the convex shape structs in [`crate::types::shapes`], which are swept inline without being added to the simulation,
+/// and for handles to convex shapes, which sweep a shape already stored in the simulation. The trait is sealed.
+pub trait SweepShape: sealed::Sealed {
+    #[doc(hidden)]
+    unsafe fn sweep(
+        &self,
+        simulation: &Simulation,
+        pose: RigidPose,
+        velocity: BodyVelocity,
+        max_t: f32,
+        handler: *mut SweepHitHandler,
+    ) -> bool;
+}
+
+macro_rules! impl_sweep_shape {
+    ($($shape:ty),*) => {
+        $(
+            impl sealed::Sealed for $shape {}
+
+            impl SweepShape for $shape {
+                unsafe fn sweep(
+                    &self,
+                    simulation: &Simulation,
+                    pose: RigidPose,
+                    velocity: BodyVelocity,
+                    max_t: f32,
+                    handler: *mut SweepHitHandler,
+                ) -> bool {
+                    crate::ffi::queries::sweep(
+                        simulation.handle,
+                        self as *const Self as *const (),
+                        <Self as Shape>::TYPE as i32,
+                        pose,
+                        velocity,
+                        max_t,
+                        handler,
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_sweep_shape!(Sphere, Capsule, Box, Triangle, Cylinder);
+
+impl<T: ConvexShape> sealed::Sealed for ShapeHandle<T> {}
+
+impl<T: ConvexShape> SweepShape for ShapeHandle<T> {
+    unsafe fn sweep(
+        &self,
+        simulation: &Simulation,
+        pose: RigidPose,
+        velocity: BodyVelocity,
+        max_t: f32,
+        handler: *mut SweepHitHandler,
+    ) -> bool {
+        self.check_owner(simulation);
+        crate::ffi::queries::sweep_indexed(
+            simulation.handle,
+            self.index(),
+            pose,
+            velocity,
+            max_t,
+            handler,
+        )
+    }
+}
+
+/// Rust closures driving a query, reached from the native callbacks through the handler's context pointer.
+struct QueryContext<F, H> {
+    filter: F,
+    on_hit: H,
+}
+
+unsafe extern "C" fn query_allow_test<F, H>(
+    context: *mut (),
+    collidable: CollidableReference,
+) -> bool
+where
+    F: FnMut(CollidableReference) -> bool,
+{
+    let context = &mut *(context as *mut QueryContext<F, H>);
+    (context.filter)(collidable)
+}
+
+unsafe extern "C" fn query_on_hit<F, H, T>(context: *mut (), hit: *const T, maximum_t: *mut f32)
+where
+    H: FnMut(&T, &mut f32),
+{
+    let context = &mut *(context as *mut QueryContext<F, H>);
+    (context.on_hit)(&*hit, &mut *maximum_t)
+}
+
+unsafe extern "C" fn overlap_on_overlap<F>(
+    context: *mut (),
+    collidable: CollidableReference,
+) -> bool
+where
+    F: FnMut(CollidableReference) -> bool,
+{
+    (*(context as *mut F))(collidable)
+}
+
+impl Simulation {
+    /// Casts a ray against the simulation and returns the closest hit, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin`: Origin of the ray.
+    /// * `direction`: Direction of the ray. Hit distances are measured in units of this vector's length.
+    /// * `max_t`: Maximum distance along the ray to test.
+    /// * `filter`: Called for each collidable whose bounds the ray passes through. Returning false skips the collidable.
+    ///
+    /// returns: The closest hit, or the error Bepu raised during the query.
+    ///
+    /// # Remarks
+    ///
+    /// Panicking inside `filter` aborts the process, since the panic can't unwind through the native frames.
+    pub fn ray_cast(
+        &self,
+        origin: Vector3,
+        direction: Vector3,
+        max_t: f32,
+        filter: impl FnMut(CollidableReference) -> bool,
+    ) -> Result<Option<RayHit>, Error> {
+        let mut closest: Option<RayHit> = None;
+        self.ray_cast_with(origin, direction, max_t, filter, |hit, maximum_t| {
+            if closest.is_none_or(|closest| hit.t < closest.t) {
+                closest = Some(*hit);
+                // Anything further away than this hit can't be the closest, so stop traversing it.
+                *maximum_t = hit.t;
+            }
+        })?;
+        Ok(closest)
+    }
+
+    /// Casts a ray against the simulation and reports every hit to a closure.
+    ///
+    /// Hits are reported in traversal order, not sorted by distance.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin`: Origin of the ray.
+    /// * `direction`: Direction of the ray. Hit distances are measured in units of this vector's length.
+    /// * `max_t`: Maximum distance along the ray to test.
+    /// * `filter`: Called for each collidable whose bounds the ray passes through. Returning false skips the collidable.
+    /// * `on_hit`: Called for every intersection found.
+    ///
+    /// returns: The error Bepu raised during the query, if any.
+    ///
+    /// # Remarks
+    ///
+    /// Panicking inside either closure aborts the process, since the panic can't unwind through the native frames.
+    pub fn ray_cast_all(
+        &self,
+        origin: Vector3,
+        direction: Vector3,
+        max_t: f32,
+        filter: impl FnMut(CollidableReference) -> bool,
+        mut on_hit: impl FnMut(&RayHit),
+    ) -> Result<(), Error> {
+        self.ray_cast_with(origin, direction, max_t, filter, |hit, _| on_hit(hit))
+    }
+
+    fn ray_cast_with<F, H>(
+        &self,
+        origin: Vector3,
+        direction: Vector3,
+        max_t: f32,
+        filter: F,
+        on_hit: H,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(CollidableReference) -> bool,
+        H: FnMut(&RayHit, &mut f32),
+    {
+        let mut context = QueryContext { filter, on_hit };
+        let mut handler = RayHitHandler {
+            context: &mut context as *mut QueryContext<F, H> as *mut (),
+            allow_test: Some(query_allow_test::<F, H>),
+            on_ray_hit: query_on_hit::<F, H, RayHit>,
+        };
+        // SAFETY:
+        //
+        // The context outlives the call and the callbacks are instantiated for its exact type.
+        // The query runs synchronously on this thread, so the closures are never called concurrently.
+        let completed = unsafe {
+            crate::ffi::queries::ray_cast(self.handle, origin, direction, max_t, &mut handler)
+        };
+        if completed {
+            Ok(())
+        } else {
+            Err(Error::take_native())
+        }
+    }
+
+    /// Sweeps a convex shape through the simulation and returns the earliest impact, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape`: Shape to sweep; either an inline convex shape or the handle of a convex shape stored in the simulation.
+    /// * `pose`: Starting pose of the shape.
+    /// * `velocity`: Linear and angular velocity of the shape over the sweep.
+    /// * `max_t`: Maximum time of impact to test.
+    /// * `filter`: Called for each collidable whose bounds the sweep passes through. Returning false skips the collidable.
+    ///
+    /// returns: The earliest impact, or the error Bepu raised during the query.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shape` is a handle belonging to another simulation.
+    ///
+    /// # Remarks
+    ///
+    /// Panicking inside `filter` aborts the process, since the panic can't unwind through the native frames.
+    pub fn sweep(
+        &self,
+        shape: &impl SweepShape,
+        pose: RigidPose,
+        velocity: BodyVelocity,
+        max_t: f32,
+        filter: impl FnMut(CollidableReference) -> bool,
+    ) -> Result<Option<SweepHit>, Error> {
+        let mut earliest: Option<SweepHit> = None;
+        self.sweep_with(shape, pose, velocity, max_t, filter, |hit, maximum_t| {
+            if earliest.is_none_or(|earliest| hit.t < earliest.t) {
+                earliest = Some(*hit);
+                // Anything hit later than this can't be the earliest impact, so stop traversing it.
+                *maximum_t = hit.t;
+            }
+        })?;
+        Ok(earliest)
+    }
+
+    /// Sweeps a convex shape through the simulation and reports every impact to a closure.
+    ///
+    /// Hits are reported in traversal order, not sorted by time of impact.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape`: Shape to sweep; either an inline convex shape or the handle of a convex shape stored in the simulation.
+    /// * `pose`: Starting pose of the shape.
+    /// * `velocity`: Linear and angular velocity of the shape over the sweep.
+    /// * `max_t`: Maximum time of impact to test.
+    /// * `filter`: Called for each collidable whose bounds the sweep passes through. Returning false skips the collidable.
+    /// * `on_hit`: Called for every impact found.
+    ///
+    /// returns: The error Bepu raised during the query, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shape` is a handle belonging to another simulation.
+    ///
+    /// # Remarks
+    ///
+    /// Panicking inside either closure aborts the process, since the panic can't unwind through the native frames.
+    pub fn sweep_all(
+        &self,
+        shape: &impl SweepShape,
+        pose: RigidPose,
+        velocity: BodyVelocity,
+        max_t: f32,
+        filter: impl FnMut(CollidableReference) -> bool,
+        mut on_hit: impl FnMut(&SweepHit),
+    ) -> Result<(), Error> {
+        self.sweep_with(shape, pose, velocity, max_t, filter, |hit, _| on_hit(hit))
+    }
+
+    fn sweep_with<F, H>(
+        &self,
+        shape: &impl SweepShape,
+        pose: RigidPose,
+        velocity: BodyVelocity,
+        max_t: f32,
+        filter: F,
+        on_hit: H,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(CollidableReference) -> bool,
+        H: FnMut(&SweepHit, &mut f32),
+    {
+        let mut context = QueryContext { filter, on_hit };
+        let mut handler = SweepHitHandler {
+            context: &mut context as *mut QueryContext<F, H> as *mut (),
+            allow_test: Some(query_allow_test::<F, H>),
+            on_hit: query_on_hit::<F, H, SweepHit>,
+        };
+        // Sweeps allocate their working memory from the simulation's pool.
+        let _pool = self.pool.lock();
+        // SAFETY: See `Simulation::ray_cast_with`. Inline shapes are only read for the duration of the call.
+        let completed = unsafe { shape.sweep(self, pose, velocity, max_t, &mut handler) };
+        if completed {
+            Ok(())
+        } else {
+            Err(Error::take_native())
+        }
+    }
+
+    /// Reports every collidable whose broad-phase bounds overlap a box.
+    ///
+    /// Both awake and sleeping bodies are reported, along with statics. The bounds tested are the expanded bounding boxes
+    /// stored in the broad phase, so collidables near but not touching the box may be reported as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `min`: Minimum corner of the query box.
+    /// * `max`: Maximum corner of the query box.
+    /// * `on_overlap`: Called for each overlapping collidable. Returning false stops the query.
+    ///
+    /// returns: The error Bepu raised during the query, if any.
+    ///
+    /// # Remarks
+    ///
+    /// Panicking inside `on_overlap` aborts the process, since the panic can't unwind through the native frames.
+    pub fn query_aabb<F>(&self, min: Vector3, max: Vector3, mut on_overlap: F) -> Result<(), Error>
+    where
+        F: FnMut(CollidableReference) -> bool,
+    {
+        let mut handler = BroadPhaseOverlapHandler {
+            context: &mut on_overlap as *mut F as *mut (),
+            on_overlap: overlap_on_overlap::<F>,
+        };
+        // SAFETY: See `Simulation::ray_cast_with`.
+        let completed =
+            unsafe { crate::ffi::queries::query_aabb(self.handle, min, max, &mut handler) };
+        if completed {
+            Ok(())
+        } else {
+            Err(Error::take_native())
+        }
+    }
+
+    /// Reports every collidable whose broad-phase bounds overlap a sphere.
+    ///
+    /// Candidates are gathered with [`Simulation::query_aabb`] and then refined against each collidable's broad-phase bounds,
+    /// so the same caveats about expanded bounds apply.
+    ///
+    /// # Arguments
+    ///
+    /// * `center`: Center of the query sphere.
+    /// * `radius`: Radius of the query sphere.
+    /// * `on_overlap`: Called for each overlapping collidable. Returning false stops the query.
+    ///
+    /// returns: The error Bepu raised during the query, if any.
+    pub fn query_sphere<F>(
+        &self,
+        center: Vector3,
+        radius: f32,
+        mut on_overlap: F,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(CollidableReference) -> bool,
+    {
+        let min = Vector3::new(center.x - radius, center.y - radius, center.z - radius);
+        let max = Vector3::new(center.x + radius, center.y + radius, center.z + radius);
+        // A failed bounds lookup can't unwind out of the callback, so it stops the query and is reported afterwards.
+        let mut lookup_error = None;
+        self.query_aabb(min, max, |collidable| {
+            let (mut min, mut max) = (Vector3::zero(), Vector3::zero());
+            // SAFETY:
+            //
+            // The collidable was just reported by the broad phase, so its owner exists in the simulation.
+            let read = unsafe {
+                if collidable.mobility() == CollidableMobility::Static {
+                    crate::ffi::simulation::get_static_bounding_box_in_broad_phase(
+                        self.handle,
+                        collidable.static_handle(),
+                        &mut min,
+                        &mut max,
+                    )
+                } else {
+                    crate::ffi::simulation::get_body_bounding_box_in_broad_phase(
+                        self.handle,
+                        collidable.body_handle(),
+                        &mut min,
+                        &mut max,
+                    )
+                }
+            };
+            if !read {
+                lookup_error = Some(Error::take_native());
+                return false;
+            }
+            // Distance from the center to the closest point of the box.
+            let dx = (min.x - center.x).max(0.0).max(center.x - max.x);
+            let dy = (min.y - center.y).max(0.0).max(center.y - max.y);
+            let dz = (min.z - center.z).max(0.0).max(center.z - max.z);
+            if dx * dx + dy * dy + dz * dz <= radius * radius {
+                on_overlap(collidable)
+            } else {
+                true
+            }
+        })?;
+        match lookup_error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::types::simulation::SolveDescription;
+
+/// How many velocity iterations the solver runs in each substep.
+///
+/// Stiff stacks tend to converge much better when the first substep gets more iterations than the rest,
+/// without paying for them in every substep.
+pub enum IterationSchedule {
+    /// The same number of iterations in every substep.
+    Constant(i32),
+    /// One entry per substep. Substeps past the end of the table, or with a non-positive entry,
+    /// use the solver's velocity iteration count.
+    PerSubstep(Vec<i32>),
+    /// Computes the number of iterations from the substep index. Non-positive results use the solver's velocity iteration count.
+    Custom(Box<dyn Fn(i32) -> i32 + Send + Sync>),
+}
+
+impl IterationSchedule {
+    /// Creates a schedule computed by a closure.
+    pub fn custom<F: Fn(i32) -> i32 + Send + Sync + 'static>(schedule: F) -> Self {
+        IterationSchedule::Custom(Box::new(schedule))
+    }
+
+    /// Gets the number of iterations to run in a substep, or a non-positive value to fall back to the solver's count.
+    pub fn iterations(&self, substep_index: i32) -> i32 {
+        match self {
+            IterationSchedule::Constant(iterations) => *iterations,
+            IterationSchedule::PerSubstep(table) => usize::try_from(substep_index)
+                .ok()
+                .and_then(|index| table.get(index))
+                .copied()
+                .unwrap_or(0),
+            IterationSchedule::Custom(schedule) => schedule(substep_index),
+        }
+    }
+
+    /// Points a solve description at this schedule.
+    ///
+    /// # Safety
+    ///
+    /// The schedule must not move or be dropped while a simulation created from the description is alive.
+    pub(crate) unsafe fn install(&self, description: &mut SolveDescription) {
+        description.velocity_iteration_scheduler = Some(schedule_velocity_iterations);
+        description.velocity_iteration_scheduler_context = self as *const Self as *mut ();
+    }
+}
+
+unsafe extern "C" fn schedule_velocity_iterations(context: *mut (), substep_index: i32) -> i32 {
+    // Only installed by `IterationSchedule::install`, so the context points to a live schedule.
+    (*(context as *const IterationSchedule)).iterations(substep_index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constant_ignores_substep_index() {
+        let schedule = IterationSchedule::Constant(4);
+        assert_eq!(schedule.iterations(0), 4);
+        assert_eq!(schedule.iterations(100), 4);
+        assert_eq!(schedule.iterations(-1), 4);
+    }
+
+    #[test]
+    fn per_substep_falls_back_outside_table() {
+        let schedule = IterationSchedule::PerSubstep(vec![8, 2, -3]);
+        assert_eq!(schedule.iterations(0), 8);
+        assert_eq!(schedule.iterations(1), 2);
+        assert_eq!(schedule.iterations(2), -3);
+        assert_eq!(schedule.iterations(3), 0);
+        assert_eq!(schedule.iterations(-1), 0);
+        assert_eq!(schedule.iterations(i32::MIN), 0);
+    }
+
+    #[test]
+    fn per_substep_empty_table_falls_back() {
+        let schedule = IterationSchedule::PerSubstep(Vec::new());
+        assert_eq!(schedule.iterations(0), 0);
+    }
+
+    #[test]
+    fn custom_receives_substep_index() {
+        let schedule = IterationSchedule::custom(|index| if index == 0 { 6 } else { index - 10 });
+        assert_eq!(schedule.iterations(0), 6);
+        assert_eq!(schedule.iterations(3), -7);
+        assert_eq!(schedule.iterations(-5), -15);
+    }
+
+    #[test]
+    fn installed_scheduler_reads_schedule() {
+        let schedule = IterationSchedule::PerSubstep(vec![5, 1]);
+        let mut description = SolveDescription::default();
+        // SAFETY:
+        //
+        // The schedule outlives every use of the description below.
+        unsafe { schedule.install(&mut description) };
+        let scheduler = description.velocity_iteration_scheduler.unwrap();
+        let context = description.velocity_iteration_scheduler_context;
+        // SAFETY:
+        //
+        // The context was set by `install` to the live schedule above.
+        unsafe {
+            assert_eq!(scheduler(context, 0), 5);
+            assert_eq!(scheduler(context, 1), 1);
+            assert_eq!(scheduler(context, 2), 0);
+        }
+    }
+}
@@ -0,0 +1,453 @@
+use std::{marker::PhantomData, mem::MaybeUninit};
+
+use crate::{
+    bepu::buffer_pool::PooledBuffer,
+    types::{
+        handles::{SimulationHandle, TypedIndex},
+        math::scalar::Vector3,
+        shapes::*,
+        utilities::Buffer,
+    },
+    Error,
+};
+
+use super::Simulation;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A shape type that can be stored in a simulation's shape registry.
+///
+/// Implemented for every shape type in [`crate::types::shapes`]. The trait is sealed,
+/// since the simulation trusts its type id and accessors to match the native shape layout.
+pub trait Shape: sealed::Sealed + Sized {
+    /// Type id used by the simulation to identify shapes of this type.
+    const TYPE: ShapeTypes;
+    /// Whether shapes of this type hold buffers allocated from the simulation's buffer pool.
+    /// Such shapes have those buffers returned to the pool when they are removed.
+    const OWNS_POOL_MEMORY: bool;
+
+    #[doc(hidden)]
+    unsafe fn add(simulation: SimulationHandle, shape: Self) -> TypedIndex;
+
+    #[doc(hidden)]
+    unsafe fn data(simulation: SimulationHandle, index: TypedIndex) -> *mut Self;
+}
+
+/// A shape that is plain data and owns no buffer pool memory.
+///
+/// These shapes can be added, modified in place and removed freely. Sealed like [`Shape`].
+pub trait SimpleShape: Shape {}
+
+macro_rules! impl_shape {
+    ($shape:ty, $ty:ident, $owns:literal, $add:ident, $data:ident) => {
+        impl sealed::Sealed for $shape {}
+
+        impl Shape for $shape {
+            const TYPE: ShapeTypes = ShapeTypes::$ty;
+            const OWNS_POOL_MEMORY: bool = $owns;
+
+            unsafe fn add(simulation: SimulationHandle, shape: Self) -> TypedIndex {
+                let index = crate::ffi::shapes::$add(simulation, shape);
+                if !index.exists() {
+                    Error::panic_native("adding a shape");
+                }
+                index
+            }
+
+            unsafe fn data(simulation: SimulationHandle, index: TypedIndex) -> *mut Self {
+                let data = crate::ffi::shapes::$data(simulation, index);
+                if data.is_null() {
+                    Error::panic_native("reading a shape");
+                }
+                data
+            }
+        }
+    };
+}
+
+impl_shape!(Sphere, Sphere, false, add_sphere, get_sphere_shape_data);
+impl_shape!(Capsule, Capsule, false, add_capsule, get_capsule_shape_data);
+impl_shape!(Box, Box, false, add_box, get_box_shape_data);
+impl_shape!(
+    Triangle,
+    Triangle,
+    false,
+    add_triangle,
+    get_triangle_shape_data
+);
+impl_shape!(
+    Cylinder,
+    Cylinder,
+    false,
+    add_cylinder,
+    get_cylinder_shape_data
+);
+impl_shape!(
+    ConvexHull,
+    ConvexHull,
+    true,
+    add_convex_hull,
+    get_convex_hull_shape_data
+);
+impl_shape!(
+    Compound,
+    Compound,
+    true,
+    add_compound,
+    get_compound_shape_data
+);
+impl_shape!(
+    BigCompound,
+    BigCompound,
+    true,
+    add_big_compound,
+    get_big_compound_shape_data
+);
+impl_shape!(Mesh, Mesh, true, add_mesh, get_mesh_shape_data);
+
+impl SimpleShape for Sphere {}
+impl SimpleShape for Capsule {}
+impl SimpleShape for Box {}
+impl SimpleShape for Triangle {}
+impl SimpleShape for Cylinder {}
+
+/// A convex shape, which can be swept through the simulation by handle. Sealed like [`Shape`].
+pub trait ConvexShape: Shape {}
+
+impl ConvexShape for Sphere {}
+impl ConvexShape for Capsule {}
+impl ConvexShape for Box {}
+impl ConvexShape for Triangle {}
+impl ConvexShape for Cylinder {}
+impl ConvexShape for ConvexHull {}
+
+/// A typed handle to a shape stored in a simulation.
+///
+/// Handles are deliberately not `Copy`; removing a shape consumes its handle so it can't be read after removal.
+/// Use [`ShapeHandle::index`] to reference the shape from bodies, statics and compound children.
+///
+/// A handle remembers the simulation it was created by, and using it with any other simulation panics.
+#[must_use = "dropping a shape handle leaks the shape until the simulation is destroyed"]
+pub struct ShapeHandle<T: Shape> {
+    simulation: SimulationHandle,
+    index: TypedIndex,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Shape> ShapeHandle<T> {
+    /// Recovers a typed handle from an untyped shape index.
+    ///
+    /// Returns `None` if the index points to nothing or to a shape of another type.
+    ///
+    /// # Safety
+    ///
+    /// The index must refer to a shape that is currently alive in `simulation`,
+    /// and no other handle may exist for the same shape.
+    pub unsafe fn from_index(simulation: &Simulation, index: TypedIndex) -> Option<Self> {
+        if index.exists() && index.ty() == T::TYPE as i32 {
+            Some(Self {
+                simulation: simulation.handle,
+                index,
+                _marker: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Gets the untyped index of the shape.
+    pub fn index(&self) -> TypedIndex {
+        self.index
+    }
+
+    /// Panics if the handle was created by another simulation.
+    #[track_caller]
+    pub(super) fn check_owner(&self, simulation: &Simulation) {
+        assert!(
+            self.simulation == simulation.handle,
+            "shape handle belongs to another simulation"
+        );
+    }
+}
+
+impl Simulation {
+    /// Gets read-only access to the shapes stored in the simulation.
+    pub fn shapes(&self) -> Shapes<'_> {
+        Shapes { simulation: self }
+    }
+
+    /// Gets mutable access to the shapes stored in the simulation.
+    pub fn shapes_mut(&mut self) -> ShapesMut<'_> {
+        ShapesMut { simulation: self }
+    }
+}
+
+/// Read-only access to the shapes of a [`Simulation`].
+pub struct Shapes<'a> {
+    simulation: &'a Simulation,
+}
+
+impl<'a> Shapes<'a> {
+    /// Gets the data of a shape.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle belongs to another simulation.
+    pub fn get<T: Shape>(&self, handle: &ShapeHandle<T>) -> &'a T {
+        handle.check_owner(self.simulation);
+        // SAFETY:
+        //
+        // The handle was typed when the shape was added to this simulation, checked above, and handles are consumed
+        // on removal, so the index points to a live shape of type `T`. The simulation is immutably borrowed for 'a.
+        unsafe { &*T::data(self.simulation.handle, handle.index) }
+    }
+}
+
+/// Mutable access to the shapes of a [`Simulation`].
+pub struct ShapesMut<'a> {
+    simulation: &'a mut Simulation,
+}
+
+impl<'a> ShapesMut<'a> {
+    /// Gets the data of a shape.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle belongs to another simulation.
+    pub fn get<T: Shape>(&self, handle: &ShapeHandle<T>) -> &T {
+        handle.check_owner(self.simulation);
+        // SAFETY: See `Shapes::get`.
+        unsafe { &*T::data(self.simulation.handle, handle.index) }
+    }
+
+    /// Gets the data of a shape for modification.
+    ///
+    /// Only shapes that own no pool memory can be modified in place; replacing the buffers of a hull, compound or mesh
+    /// would make its removal return the wrong memory to the pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle belongs to another simulation.
+    pub fn get_mut<T: SimpleShape>(&mut self, handle: &ShapeHandle<T>) -> &mut T {
+        handle.check_owner(self.simulation);
+        // SAFETY: See `Shapes::get`. The simulation is uniquely borrowed through `self`.
+        unsafe { &mut *T::data(self.simulation.handle, handle.index) }
+    }
+
+    /// Adds a shape to the simulation.
+    pub fn add<T: SimpleShape>(&mut self, shape: T) -> ShapeHandle<T> {
+        // SAFETY:
+        //
+        // Simple shapes are plain data, so there is nothing the simulation could misinterpret.
+        unsafe { self.add_unchecked(shape) }
+    }
+
+    /// Adds a shape that owns buffer pool memory to the simulation.
+    ///
+    /// # Safety
+    ///
+    /// Every buffer held by the shape must have been allocated from the buffer pool the simulation was created with,
+    /// since that pool is the one the buffers are returned to when the shape is removed.
+    pub unsafe fn add_unchecked<T: Shape>(&mut self, shape: T) -> ShapeHandle<T> {
+        let _pool = self.simulation.pool.lock();
+        ShapeHandle {
+            simulation: self.simulation.handle,
+            index: T::add(self.simulation.handle, shape),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Builds a convex hull from a point cloud and adds it to the simulation.
+    ///
+    /// Returns the handle of the hull along with the center of mass that was subtracted from the points,
+    /// or the error Bepu raised while building the hull, for example for a degenerate point cloud.
+    pub fn add_convex_hull(
+        &mut self,
+        points: &[Vector3],
+    ) -> Result<(ShapeHandle<ConvexHull>, Vector3), Error> {
+        let mut center_of_mass = Vector3::zero();
+        let mut hull = MaybeUninit::<ConvexHull>::uninit();
+        // SAFETY:
+        //
+        // The points buffer is only read for the duration of the call, so it can safely point at Rust memory.
+        // The hull is allocated from the simulation's pool, which is the pool it will be returned to on removal,
+        // and is only read once creation reported success.
+        unsafe {
+            let created = {
+                let _pool = self.simulation.pool.lock();
+                crate::ffi::shapes::create_convex_hull(
+                    self.simulation.pool.handle(),
+                    Buffer::from_raw(points.as_ptr() as *mut Vector3, points.len() as i32, -1),
+                    &mut center_of_mass,
+                    hull.as_mut_ptr(),
+                )
+            };
+            if !created {
+                return Err(Error::take_native());
+            }
+            Ok((self.add_unchecked(hull.assume_init()), center_of_mass))
+        }
+    }
+
+    /// Builds a mesh from a buffer of triangles and adds it to the simulation.
+    ///
+    /// The mesh takes ownership of the triangles, which are returned to the pool when the mesh is removed.
+    ///
+    /// returns: The handle of the mesh, or the error Bepu raised while building it. On failure the triangles are returned to the pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the triangles were not allocated from the simulation's buffer pool.
+    pub fn add_mesh(
+        &mut self,
+        triangles: PooledBuffer<'_, Triangle>,
+        scale: Vector3,
+    ) -> Result<ShapeHandle<Mesh>, Error> {
+        assert!(
+            std::ptr::eq(triangles.pool(), &*self.simulation.pool),
+            "triangles must be allocated from the simulation's buffer pool"
+        );
+        // SAFETY:
+        //
+        // The triangles and the mesh tree are allocated from the simulation's pool, checked above,
+        // which is the pool they will be returned to on removal.
+        // Ownership of the triangles is only released once the mesh holds them.
+        unsafe {
+            let mut mesh = MaybeUninit::<Mesh>::uninit();
+            // The pool is unlocked before the triangles can be dropped, since returning them takes the lock.
+            let created = {
+                let _pool = self.simulation.pool.lock();
+                crate::ffi::shapes::create_mesh(
+                    self.simulation.pool.handle(),
+                    triangles.as_raw(),
+                    scale,
+                    mesh.as_mut_ptr(),
+                )
+            };
+            if !created {
+                return Err(Error::take_native());
+            }
+            triangles.into_buffer();
+            Ok(self.add_unchecked(mesh.assume_init()))
+        }
+    }
+
+    /// Builds a big compound from a buffer of children and adds it to the simulation.
+    ///
+    /// The compound takes ownership of the children, which are returned to the pool when the compound is removed.
+    ///
+    /// returns: The handle of the compound, or the error Bepu raised while building it. On failure the children are returned to the pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the children were not allocated from the simulation's buffer pool.
+    ///
+    /// # Safety
+    ///
+    /// Every child must reference a shape that is currently alive in this simulation,
+    /// since the compound reads the child shapes to build its acceleration structure.
+    pub unsafe fn add_big_compound(
+        &mut self,
+        children: PooledBuffer<'_, CompoundChild>,
+    ) -> Result<ShapeHandle<BigCompound>, Error> {
+        assert!(
+            std::ptr::eq(children.pool(), &*self.simulation.pool),
+            "children must be allocated from the simulation's buffer pool"
+        );
+        let mut compound = MaybeUninit::<BigCompound>::uninit();
+        // Unlocked before the children can be dropped, as in `ShapesMut::add_mesh`.
+        let created = {
+            let _pool = self.simulation.pool.lock();
+            crate::ffi::shapes::create_big_compound(
+                self.simulation.handle,
+                self.simulation.pool.handle(),
+                children.as_raw(),
+                compound.as_mut_ptr(),
+            )
+        };
+        if !created {
+            return Err(Error::take_native());
+        }
+        // The compound holds the children now.
+        children.into_buffer();
+        Ok(self.add_unchecked(compound.assume_init()))
+    }
+
+    /// Removes a shape that owns no pool memory from the simulation.
+    ///
+    /// Bodies and statics still using the shape keep colliding with its last data until their shape is changed,
+    /// or with a shape of the same type added later into the freed slot. Either way they only read shape batch memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle belongs to another simulation.
+    pub fn remove<T: SimpleShape>(&mut self, handle: ShapeHandle<T>) {
+        handle.check_owner(self.simulation);
+        let _pool = self.simulation.pool.lock();
+        // SAFETY:
+        //
+        // The handle is consumed, so this is the only removal of the shape.
+        // Simple shapes own no buffers, so nothing referencing the slot can read freed memory.
+        let removed =
+            unsafe { crate::ffi::shapes::remove_shape(self.simulation.handle, handle.index) };
+        if !removed {
+            Error::panic_native("removing a shape");
+        }
+    }
+
+    /// Removes a shape from the simulation, returning any buffers it owns to the simulation's buffer pool.
+    ///
+    /// Child shapes referenced by compounds are left in the simulation; see [`ShapesMut::remove_recursively`].
+    ///
+    /// # Safety
+    ///
+    /// No body, static or compound child in the simulation may still reference the shape. The next step would read
+    /// the returned buffers of a hull, compound or mesh; remove those collidables or change their shape first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle belongs to another simulation.
+    pub unsafe fn remove_and_destroy<T: Shape>(&mut self, handle: ShapeHandle<T>) {
+        handle.check_owner(self.simulation);
+        let _pool = self.simulation.pool.lock();
+        let removed = if T::OWNS_POOL_MEMORY {
+            // Shapes owning pool memory were allocated from the simulation's pool.
+            crate::ffi::shapes::remove_and_destroy_shape(
+                self.simulation.handle,
+                self.simulation.pool.handle(),
+                handle.index,
+            )
+        } else {
+            crate::ffi::shapes::remove_shape(self.simulation.handle, handle.index)
+        };
+        if !removed {
+            Error::panic_native("removing a shape");
+        }
+    }
+
+    /// Removes a shape along with every child shape it references.
+    ///
+    /// Any buffers owned by the removed shapes are returned to the simulation's buffer pool.
+    ///
+    /// # Safety
+    ///
+    /// Handles to child shapes are not consumed, so the caller must make sure no handle to a child is used afterwards.
+    /// As with [`ShapesMut::remove_and_destroy`], nothing in the simulation may still reference the shape or any of its children.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle belongs to another simulation.
+    pub unsafe fn remove_recursively<T: Shape>(&mut self, handle: ShapeHandle<T>) {
+        handle.check_owner(self.simulation);
+        let _pool = self.simulation.pool.lock();
+        if !crate::ffi::shapes::remove_and_destroy_shape_recursively(
+            self.simulation.handle,
+            self.simulation.pool.handle(),
+            handle.index,
+        ) {
+            Error::panic_native("removing a shape");
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use std::mem::MaybeUninit;
+
+use crate::{
+    types::{
+        ccd::ContinuousDetection,
+        handles::{StaticHandle, TypedIndex},
+        math::scalar::RigidPose,
+        statics::{Static, StaticDescription},
+        utilities::Buffer,
+    },
+    Error,
+};
+
+use super::Simulation;
+
+impl Simulation {
+    /// Adds a static collidable to the simulation, returning a handle that can be used to access it later.
+    ///
+    /// returns: The handle of the static, or the error Bepu raised while adding it.
+    /// A shape that doesn't exist in this simulation is an [`Error::InvalidHandle`].
+    pub fn add_static(&mut self, description: StaticDescription) -> Result<StaticHandle, Error> {
+        self.validate_shape(description.shape)?;
+        let _pool = self.pool.lock();
+        // SAFETY:
+        //
+        // The simulation handle is owned by `self` and is valid for as long as `self` is alive.
+        let handle = unsafe { crate::ffi::simulation::add_static(self.handle, description) };
+        if handle.value < 0 {
+            return Err(Error::take_native());
+        }
+        Ok(handle)
+    }
+
+    /// Removes a static collidable from the simulation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle does not refer to a static in this simulation.
+    pub fn remove_static(&mut self, handle: StaticHandle) {
+        assert!(
+            self.contains_static(handle),
+            "static handle does not exist in this simulation"
+        );
+        let _pool = self.pool.lock();
+        // SAFETY:
+        //
+        // The handle was checked against the simulation's handle mapping above.
+        let removed = unsafe { crate::ffi::simulation::remove_static(self.handle, handle) };
+        if !removed {
+            Error::panic_native("removing a static");
+        }
+    }
+
+    /// Gets whether the handle refers to a static that currently exists in the simulation.
+    pub fn contains_static(&self, handle: StaticHandle) -> bool {
+        let mut mapping = Buffer::<i32>::new();
+        // SAFETY:
+        //
+        // The mapping buffer is only read before any further calls into the simulation,
+        // so it can't be invalidated by a resize while in use.
+        let read = unsafe {
+            crate::ffi::simulation::get_static_handle_to_location_mapping(self.handle, &mut mapping)
+        };
+        if !read {
+            Error::panic_native("reading the static handle mapping");
+        }
+        // Removed statics and never-allocated slots both map to a negative index.
+        handle.value >= 0 && handle.value < mapping.len && mapping[handle.value] >= 0
+    }
+
+    /// Gets a read-only view of a static.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle does not refer to a static in this simulation.
+    pub fn static_ref(&self, handle: StaticHandle) -> StaticRef<'_> {
+        assert!(
+            self.contains_static(handle),
+            "static handle does not exist in this simulation"
+        );
+        StaticRef {
+            simulation: self,
+            handle,
+        }
+    }
+
+    /// Moves a static to a new pose, updating its bounds in the broad phase.
+    ///
+    /// returns: The error Bepu raised while moving the static, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle does not refer to a static in this simulation.
+    pub fn set_static_pose(&mut self, handle: StaticHandle, pose: RigidPose) -> Result<(), Error> {
+        let mut description = self.static_ref(handle).description();
+        description.pose = pose;
+        self.apply_static_description(handle, description)
+    }
+
+    /// Applies a description to a static, updating every property and notifying the broad phase of the change.
+    ///
+    /// returns: The error Bepu raised while applying the description, if any.
+    /// A shape that doesn't exist in this simulation is an [`Error::InvalidHandle`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle does not refer to a static in this simulation.
+    pub fn apply_static_description(
+        &mut self,
+        handle: StaticHandle,
+        description: StaticDescription,
+    ) -> Result<(), Error> {
+        assert!(
+            self.contains_static(handle),
+            "static handle does not exist in this simulation"
+        );
+        self.validate_shape(description.shape)?;
+        let _pool = self.pool.lock();
+        // SAFETY:
+        //
+        // The handle was checked against the simulation's handle mapping above.
+        let applied = unsafe {
+            crate::ffi::simulation::apply_static_description(self.handle, handle, description)
+        };
+        if applied {
+            Ok(())
+        } else {
+            Err(Error::take_native())
+        }
+    }
+}
+
+/// A read-only view of a static living in a [`Simulation`].
+///
+/// The view borrows the simulation, so the memory it points to can't be moved by adding or removing statics while it is alive.
+pub struct StaticRef<'a> {
+    simulation: &'a Simulation,
+    handle: StaticHandle,
+}
+
+impl<'a> StaticRef<'a> {
+    /// Gets the handle of the static.
+    pub fn handle(&self) -> StaticHandle {
+        self.handle
+    }
+
+    /// Gets the simulation data of the static.
+    pub fn data(&self) -> &'a Static {
+        // SAFETY:
+        //
+        // The handle was validated when the view was created and the simulation is immutably borrowed for 'a,
+        // so the static can't be removed or moved in memory while the reference is alive.
+        unsafe {
+            let data = crate::ffi::simulation::get_static(self.simulation.handle, self.handle);
+            if data.is_null() {
+                Error::panic_native("reading a static");
+            }
+            &*data
+        }
+    }
+
+    /// Gets the pose of the static.
+    pub fn pose(&self) -> &'a RigidPose {
+        &self.data().pose
+    }
+
+    /// Gets the shape of the static.
+    pub fn shape(&self) -> TypedIndex {
+        self.data().shape
+    }
+
+    /// Gets the continuous collision detection settings of the static.
+    pub fn continuity(&self) -> &'a ContinuousDetection {
+        &self.data().continuity
+    }
+
+    /// Builds a description of the static's current state.
+    pub fn description(&self) -> StaticDescription {
+        let mut description = MaybeUninit::<StaticDescription>::uninit();
+        // SAFETY: See `StaticRef::data`. The description is only read once the call reported success.
+        unsafe {
+            if !crate::ffi::simulation::get_static_description(
+                self.simulation.handle,
+                self.handle,
+                description.as_mut_ptr(),
+            ) {
+                Error::panic_native("reading a static's description");
+            }
+            description.assume_init()
+        }
+    }
+}
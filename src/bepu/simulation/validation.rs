@@ -0,0 +1,46 @@
+use crate::{types::handles::TypedIndex, Error};
+
+use super::Simulation;
+
+impl Simulation {
+    /// Checks whether a shape index can be used with this simulation.
+    ///
+    /// Catches indices that point to nothing, to an unregistered shape type, or past the end of their shape batch.
+    /// Bepu doesn't track which slots of a shape batch are free, so an index to a removed shape is only caught
+    /// if the batch has shrunk since.
+    ///
+    /// returns: An [`Error::InvalidHandle`] describing why the index can't be used, if it can't.
+    pub fn validate_shape(&self, shape: TypedIndex) -> Result<(), Error> {
+        // SAFETY:
+        //
+        // The simulation handle is owned by `self`, and the native side checks the index before touching any shape data.
+        if unsafe { crate::ffi::validation::validate_shape(self.handle, shape) } {
+            Ok(())
+        } else {
+            Err(Error::take_native())
+        }
+    }
+
+    /// Like [`Simulation::validate_shape`], but also accepts an index that refers to no shape, as a body's collidable may.
+    pub(crate) fn validate_optional_shape(&self, shape: TypedIndex) -> Result<(), Error> {
+        if shape.exists() {
+            self.validate_shape(shape)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Fails if the native library wasn't built with the handle checks the `checked-handles` feature asks for.
+pub(crate) fn check_native_validation() -> Result<(), Error> {
+    if cfg!(feature = "checked-handles") {
+        // SAFETY:
+        //
+        // Reading a build flag has no preconditions.
+        let enabled = unsafe { crate::ffi::validation::is_validation_enabled() };
+        if !enabled {
+            return Err(Error::NativeValidationDisabled);
+        }
+    }
+    Ok(())
+}
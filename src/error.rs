@@ -0,0 +1,101 @@
+use std::fmt;
+
+use crate::bepu::simulation::ConfigError;
+
+/// An error reported by the safe API.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A simulation was configured with invalid settings.
+    Config(ConfigError),
+    /// Bepu threw an exception. Holds the exception's type and message.
+    Native(String),
+    /// A handle or shape index doesn't refer to anything in the simulation it was used with. Holds the reason.
+    InvalidHandle(String),
+    /// The native library was compiled for a different SIMD width than the wide callback types.
+    SimdWidthMismatch {
+        /// Number of lanes the native library uses.
+        native: usize,
+        /// Number of lanes the wide callback types were compiled with.
+        expected: usize,
+    },
+    /// The `checked-handles` feature is enabled, but the native library was built without handle checks.
+    NativeValidationDisabled,
+}
+
+impl Error {
+    /// Takes the error recorded by the last failed native call on this thread.
+    ///
+    /// Errors recorded by a failed handle check become [`Error::InvalidHandle`], anything else [`Error::Native`].
+    pub(crate) fn take_native() -> Self {
+        // SAFETY:
+        //
+        // Reading the kind of the recorded error has no preconditions, and must happen before the message is taken.
+        if unsafe { crate::ffi::errors::is_last_error_invalid_handle() } {
+            Error::InvalidHandle(Self::take_message())
+        } else {
+            Error::Native(Self::take_message())
+        }
+    }
+
+    /// Panics with the error recorded by the last failed native call on this thread.
+    ///
+    /// For calls whose arguments the safe API has already checked, where a failure means a bug rather than a misuse.
+    #[track_caller]
+    pub(crate) fn panic_native(operation: &str) -> ! {
+        panic!("{operation} failed: {}", Self::take_native())
+    }
+
+    fn take_message() -> String {
+        // SAFETY:
+        //
+        // The buffer is sized from the reported length and the native side never writes past the given length.
+        let message = unsafe {
+            let length = crate::ffi::errors::get_last_error_length();
+            let mut buffer = vec![0u8; length.max(0) as usize];
+            let written = crate::ffi::errors::take_last_error(buffer.as_mut_ptr(), length);
+            buffer.truncate(written.max(0) as usize);
+            String::from_utf8_lossy(&buffer).into_owned()
+        };
+        if message.is_empty() {
+            "native call failed without recording an error".to_owned()
+        } else {
+            message
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Config(error) => write!(f, "invalid configuration: {error}"),
+            Error::Native(message) => write!(f, "bepu error: {message}"),
+            Error::InvalidHandle(message) => write!(f, "invalid handle: {message}"),
+            Error::SimdWidthMismatch { native, expected } => write!(
+                f,
+                "the native library was compiled for a SIMD width of {native} lanes, but the wide callback types use {expected}"
+            ),
+            Error::NativeValidationDisabled => write!(
+                f,
+                "the `checked-handles` feature is enabled, but the native library was built without handle checks"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Config(error) => Some(error),
+            Error::Native(_)
+            | Error::InvalidHandle(_)
+            | Error::SimdWidthMismatch { .. }
+            | Error::NativeValidationDisabled => None,
+        }
+    }
+}
+
+impl From<ConfigError> for Error {
+    fn from(error: ConfigError) -> Self {
+        Error::Config(error)
+    }
+}
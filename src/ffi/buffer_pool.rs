@@ -1,6 +1,6 @@
 use crate::types::{handles::BufferPoolHandle, utilities::ByteBuffer};
 
-extern "C" {
+native_functions! {
     /// Creates a new buffer pool.
     ///
     /// # Arguments
@@ -10,6 +10,10 @@ extern "C" {
     /// Buffers will be suballocated from blocks.
     /// * `expected_used_slot_count_per_pool`: Number of suballocations to preallocate reference space for.
     /// This does not preallocate actual blocks, just the space to hold references that are waiting in the pool.
+    ///
+    /// # Returns
+    ///
+    /// The created buffer pool, or a null handle if creation failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "BufferPool.Create"]
     pub fn create_buffer_pool(
         minimum_block_allocation_size: i32,
@@ -20,15 +24,23 @@ extern "C" {
     /// # Arguments
     ///
     /// * `handle`: Buffer pool to clear.
+    ///
+    /// # Returns
+    ///
+    /// True if the pool was cleared, false if clearing failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "BufferPool.Clear"]
-    pub fn clear_buffer_pool(handle: BufferPoolHandle);
+    pub fn clear_buffer_pool(handle: BufferPoolHandle) -> bool;
     /// Releases all allocations held by the buffer pool and releases the buffer pool reference. The handle is invalidated.
     ///
     /// # Arguments
     ///
     /// * `handle`: Buffer pool to destroy.
+    ///
+    /// # Returns
+    ///
+    /// True if the pool was destroyed, false if destroying failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "BufferPool.Destroy"]
-    pub fn destroy_buffer_pool(handle: BufferPoolHandle);
+    pub fn destroy_buffer_pool(handle: BufferPoolHandle) -> bool;
     /// Allocates a buffer from the buffer pool of the given size.
     ///
     /// # Arguments
@@ -38,7 +50,7 @@ extern "C" {
     ///
     /// # Returns
     ///
-    /// Allocated buffer.
+    /// Allocated buffer, or a buffer with null memory if allocating failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "BufferPool.Allocate"]
     pub fn allocate(buffer_pool_handle: BufferPoolHandle, size_in_bytes: i32) -> ByteBuffer;
     /// Allocates a buffer from the buffer pool with at least the given size.
@@ -50,7 +62,7 @@ extern "C" {
     ///
     /// # Returns
     ///
-    /// Allocated buffer.
+    /// Allocated buffer, or a buffer with null memory if allocating failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "BufferPool.AllocateAtLeast"]
     pub fn allocate_at_least(
         buffer_pool_handle: BufferPoolHandle,
@@ -64,13 +76,17 @@ extern "C" {
     /// * `buffer`: Buffer to resize.
     /// * `new_size_in_bytes`: Target size of the buffer to allocate in bytes.
     /// * `copy_count`: Number of bytes to copy from the old buffer into the new buffer.
+    ///
+    /// # Returns
+    ///
+    /// True if the buffer was resized, false if resizing failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "BufferPool.Resize"]
     pub fn resize(
         buffer_pool_handle: BufferPoolHandle,
         buffer: *mut ByteBuffer,
         new_size_in_bytes: i32,
         copy_count: i32,
-    );
+    ) -> bool;
     /// Resizes a buffer from the buffer pool to at least the given size, reallocating if necessary.
     ///
     /// # Arguments
@@ -79,29 +95,41 @@ extern "C" {
     /// * `buffer`: Buffer to resize.
     /// * `target_size_in_bytes`: Target size of the buffer to allocate in bytes.
     /// * `copy_count`: Number of bytes to copy from the old buffer into the new buffer.
+    ///
+    /// # Returns
+    ///
+    /// True if the buffer was resized, false if resizing failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "BufferPool.ResizeToAtLeast"]
     pub fn resize_to_at_least(
         buffer_pool_handle: BufferPoolHandle,
         buffer: *mut ByteBuffer,
         target_size_in_bytes: i32,
         copy_count: i32,
-    );
+    ) -> bool;
     /// Returns a buffer to the buffer pool.
     ///
     /// # Arguments
     ///
     /// * `buffer_pool_handle`: Buffer pool to return the buffer to.
     /// * `buffer`: Buffer to return to the pool.
+    ///
+    /// # Returns
+    ///
+    /// True if the buffer was returned, false if returning failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "BufferPool.Deallocate"]
-    pub fn deallocate(buffer_pool_handle: BufferPoolHandle, buffer: *mut ByteBuffer);
+    pub fn deallocate(buffer_pool_handle: BufferPoolHandle, buffer: *mut ByteBuffer) -> bool;
     /// Returns a buffer to the buffer pool by its id.
     ///
     /// # Arguments
     ///
     /// * `buffer_pool_handle`: Buffer pool to return the buffer to.
     /// * `buffer_id`: Id of the buffer to return to the pool.
+    ///
+    /// # Returns
+    ///
+    /// True if the buffer was returned, false if returning failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "BufferPool.DeallocateId"]
-    pub fn deallocate_by_id(buffer_pool_handle: BufferPoolHandle, buffer_id: i32);
+    pub fn deallocate_by_id(buffer_pool_handle: BufferPoolHandle, buffer_id: i32) -> bool;
 
     /// Computes the total number of bytes allocated from native memory in this buffer pool.
     /// Includes allocated memory regardless of whether it currently has outstanding references.
@@ -109,10 +137,14 @@ extern "C" {
     /// # Arguments
     ///
     /// * `buffer_pool_handle`: Buffer pool to check the allocation size of.
+    /// * `size`: Receives the total number of bytes allocated from native memory in this buffer pool.
     ///
     /// # Returns
     ///
-    /// Total number of bytes allocated from native memory in this buffer pool.
+    /// True if the size was computed, false if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "BufferPool.GetAllocatedMemorySizeInPool"]
-    pub fn get_allocated_memory_size_in_pool(buffer_pool_handle: BufferPoolHandle) -> u64;
+    pub fn get_allocated_memory_size_in_pool(
+        buffer_pool_handle: BufferPoolHandle,
+        size: *mut u64,
+    ) -> bool;
 }
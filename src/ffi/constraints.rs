@@ -0,0 +1,723 @@
+use crate::types::{
+    constraints::{joints::*, limits::*, motors::*, servos::*},
+    handles::{BodyHandle, ConstraintHandle, SimulationHandle},
+};
+
+native_functions! {
+    /// Removes a constraint from the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to remove the constraint from.
+    /// * `constraint_handle`: Handle of the constraint to remove.
+    ///
+    /// # Returns
+    ///
+    /// True if the constraint was removed, false if removing failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.Remove"]
+    pub fn remove_constraint(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+    ) -> bool;
+    /// Checks whether a constraint handle refers to a constraint that currently exists in the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to check.
+    /// * `constraint_handle`: Handle of the constraint to look for.
+    /// * `exists`: Receives whether the constraint exists.
+    ///
+    /// # Returns
+    ///
+    /// True if the check was performed, false if the simulation lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.Exists"]
+    pub fn constraint_exists(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        exists: *mut bool,
+    ) -> bool;
+    /// Adds a ball socket to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddBallSocket"]
+    pub fn add_ball_socket(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: BallSocket,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing ball socket.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not a ball socket or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplyBallSocketDescription"]
+    pub fn apply_ball_socket_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: BallSocket,
+    ) -> bool;
+    /// Adds a hinge to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddHinge"]
+    pub fn add_hinge(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: Hinge,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing hinge.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not a hinge or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplyHingeDescription"]
+    pub fn apply_hinge_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: Hinge,
+    ) -> bool;
+    /// Adds a weld to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddWeld"]
+    pub fn add_weld(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: Weld,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing weld.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not a weld or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplyWeldDescription"]
+    pub fn apply_weld_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: Weld,
+    ) -> bool;
+    /// Adds a swivel hinge to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddSwivelHinge"]
+    pub fn add_swivel_hinge(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: SwivelHinge,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing swivel hinge.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not a swivel hinge or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplySwivelHingeDescription"]
+    pub fn apply_swivel_hinge_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: SwivelHinge,
+    ) -> bool;
+    /// Adds an angular hinge to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddAngularHinge"]
+    pub fn add_angular_hinge(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: AngularHinge,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing angular hinge.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not an angular hinge or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplyAngularHingeDescription"]
+    pub fn apply_angular_hinge_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: AngularHinge,
+    ) -> bool;
+    /// Adds a point on line servo to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddPointOnLineServo"]
+    pub fn add_point_on_line_servo(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: PointOnLineServo,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing point on line servo.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not a point on line servo or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplyPointOnLineServoDescription"]
+    pub fn apply_point_on_line_servo_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: PointOnLineServo,
+    ) -> bool;
+    /// Adds a center distance constraint to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddCenterDistanceConstraint"]
+    pub fn add_center_distance(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: CenterDistanceConstraint,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing center distance constraint.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not a center distance constraint or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplyCenterDistanceConstraintDescription"]
+    pub fn apply_center_distance_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: CenterDistanceConstraint,
+    ) -> bool;
+    /// Adds an angular motor to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddAngularMotor"]
+    pub fn add_angular_motor(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: AngularMotor,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing angular motor.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not an angular motor or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplyAngularMotorDescription"]
+    pub fn apply_angular_motor_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: AngularMotor,
+    ) -> bool;
+    /// Adds a linear axis motor to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddLinearAxisMotor"]
+    pub fn add_linear_axis_motor(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: LinearAxisMotor,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing linear axis motor.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not a linear axis motor or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplyLinearAxisMotorDescription"]
+    pub fn apply_linear_axis_motor_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: LinearAxisMotor,
+    ) -> bool;
+    /// Adds a twist motor to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddTwistMotor"]
+    pub fn add_twist_motor(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: TwistMotor,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing twist motor.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not a twist motor or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplyTwistMotorDescription"]
+    pub fn apply_twist_motor_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: TwistMotor,
+    ) -> bool;
+    /// Adds an angular servo to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddAngularServo"]
+    pub fn add_angular_servo(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: AngularServo,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing angular servo.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not an angular servo or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplyAngularServoDescription"]
+    pub fn apply_angular_servo_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: AngularServo,
+    ) -> bool;
+    /// Adds a linear axis servo to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddLinearAxisServo"]
+    pub fn add_linear_axis_servo(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: LinearAxisServo,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing linear axis servo.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not a linear axis servo or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplyLinearAxisServoDescription"]
+    pub fn apply_linear_axis_servo_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: LinearAxisServo,
+    ) -> bool;
+    /// Adds an one body linear servo to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddOneBodyLinearServo"]
+    pub fn add_one_body_linear_servo(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: OneBodyLinearServo,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing one body linear servo.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not an one body linear servo or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplyOneBodyLinearServoDescription"]
+    pub fn apply_one_body_linear_servo_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: OneBodyLinearServo,
+    ) -> bool;
+    /// Adds an one body angular servo to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddOneBodyAngularServo"]
+    pub fn add_one_body_angular_servo(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: OneBodyAngularServo,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing one body angular servo.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not an one body angular servo or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplyOneBodyAngularServoDescription"]
+    pub fn apply_one_body_angular_servo_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: OneBodyAngularServo,
+    ) -> bool;
+    /// Adds a twist servo to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddTwistServo"]
+    pub fn add_twist_servo(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: TwistServo,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing twist servo.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not a twist servo or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplyTwistServoDescription"]
+    pub fn apply_twist_servo_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: TwistServo,
+    ) -> bool;
+    /// Adds a twist limit to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddTwistLimit"]
+    pub fn add_twist_limit(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: TwistLimit,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing twist limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not a twist limit or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplyTwistLimitDescription"]
+    pub fn apply_twist_limit_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: TwistLimit,
+    ) -> bool;
+    /// Adds a swing limit to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddSwingLimit"]
+    pub fn add_swing_limit(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: SwingLimit,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing swing limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not a swing limit or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplySwingLimitDescription"]
+    pub fn apply_swing_limit_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: SwingLimit,
+    ) -> bool;
+    /// Adds a distance limit to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddDistanceLimit"]
+    pub fn add_distance_limit(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: DistanceLimit,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing distance limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not a distance limit or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplyDistanceLimitDescription"]
+    pub fn apply_distance_limit_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: DistanceLimit,
+    ) -> bool;
+    /// Adds a linear axis limit to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the constraint to.
+    /// * `body_handles`: Bodies connected by the constraint.
+    /// * `body_count`: Number of bodies pointed to by `body_handles`.
+    /// * `description`: Description of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created constraint, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.Constraints.AddLinearAxisLimit"]
+    pub fn add_linear_axis_limit(
+        simulation_handle: SimulationHandle,
+        body_handles: *const BodyHandle,
+        body_count: i32,
+        description: LinearAxisLimit,
+    ) -> ConstraintHandle;
+    /// Applies a description to an existing linear axis limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the constraint.
+    /// * `constraint_handle`: Handle of the constraint to modify.
+    /// * `description`: Description to apply.
+    ///
+    /// # Returns
+    ///
+    /// False if the constraint is not a linear axis limit or applying failed, in which case nothing is applied and an error was recorded.
+    #[link_name = "Simulation.Constraints.ApplyLinearAxisLimitDescription"]
+    pub fn apply_linear_axis_limit_description(
+        simulation_handle: SimulationHandle,
+        constraint_handle: ConstraintHandle,
+        description: LinearAxisLimit,
+    ) -> bool;
+}
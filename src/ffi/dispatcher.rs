@@ -1,6 +1,21 @@
 use crate::types::handles::ThreadDispatcherHandle;
 
-extern "C" {
+/// Runs a worker body once for every worker index below `worker_count`, concurrently, and returns once all have completed.
+///
+/// # Arguments
+///
+/// * `context`: Context pointer the dispatcher was created with.
+/// * `worker_count`: Number of workers to run.
+/// * `worker_body`: Function to call for every worker index.
+/// * `worker_context`: Opaque pointer to pass to `worker_body`.
+pub type DispatchFunction = unsafe extern "C" fn(
+    context: *mut (),
+    worker_count: i32,
+    worker_body: unsafe extern "C" fn(worker_context: *mut (), worker_index: i32),
+    worker_context: *mut (),
+);
+
+native_functions! {
 
     /// Creates a new thread dispatcher.
     ///
@@ -8,23 +23,54 @@ extern "C" {
     ///
     /// * `thread_count`: Number of threads to use within the thread dispatcher.
     /// * `thread_pool_allocation_block_size`: Minimum size in bytes of blocks allocated in per-thread buffer pools. Allocations requiring more space can result in larger block sizes, but no pools will allocate smaller blocks.
+    ///
+    /// # Returns
+    ///
+    /// The created thread dispatcher, or a null handle if creation failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "ThreadDispatcher.Create"]
     pub fn create_thread_dispatcher(
         thread_count: i32,
         thread_pool_allocation_block_size: i32,
     ) -> ThreadDispatcherHandle;
+    /// Creates a new thread dispatcher whose work is scheduled by a native job system.
+    ///
+    /// # Arguments
+    ///
+    /// * `thread_count`: Maximum number of workers the native job system can run concurrently.
+    /// * `thread_pool_allocation_block_size`: Minimum size in bytes of blocks allocated in per-thread buffer pools. Allocations requiring more space can result in larger block sizes, but no pools will allocate smaller blocks.
+    /// * `context`: Opaque pointer passed back to the dispatch function.
+    /// * `dispatch_function`: Function that runs the workers of every dispatch.
+    ///
+    /// # Returns
+    ///
+    /// The created thread dispatcher, or a null handle if creation failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "ThreadDispatcher.CreateCustom"]
+    pub fn create_custom_thread_dispatcher(
+        thread_count: i32,
+        thread_pool_allocation_block_size: i32,
+        context: *mut (),
+        dispatch_function: DispatchFunction,
+    ) -> ThreadDispatcherHandle;
     /// Releases all resources held by a thread dispatcher and invalidates its handle.
     ///
     /// # Arguments
     ///
     /// * `handle`: Thread dispatcher to destroy.
+    ///
+    /// # Returns
+    ///
+    /// True if the dispatcher was destroyed, false if destroying failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "ThreadDispatcher.Destroy"]
-    pub fn destroy_thread_dispatcher(handle: ThreadDispatcherHandle);
-    /// Releases all resources held by a thread dispatcher and invalidates its handle.
+    pub fn destroy_thread_dispatcher(handle: ThreadDispatcherHandle) -> bool;
+    /// Gets the number of worker threads owned by a thread dispatcher.
     ///
     /// # Arguments
     ///
     /// * `handle`: Thread dispatcher to check the thread count of.
+    ///
+    /// # Returns
+    ///
+    /// Number of threads in the dispatcher, or -1 if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "ThreadDispatcher.GetThreadCount"]
     pub fn get_thread_count(handle: ThreadDispatcherHandle) -> i32;
 
@@ -34,12 +80,32 @@ extern "C" {
     /// # Arguments
     ///
     /// * `thread_dispatcher_handle`: Thread dispatcher to check allocations for.
+    /// * `size`: Receives the total number of bytes allocated from native memory in this thread dispatcher's per-thread pool.
     ///
     /// # Returns
     ///
-    /// Total number of bytes allocated from native memory in this thread dispatcher's per-thread pool.
+    /// True if the size was computed, false if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "ThreadDispatcher.GetAllocatedMemorySize"]
     pub fn get_allocated_memory_size_in_thread_dispatcher(
         thread_dispatcher_handle: ThreadDispatcherHandle,
-    ) -> u64;
+        size: *mut u64,
+    ) -> bool;
+    /// Computes the number of bytes allocated from native memory in a single worker's pool of a dispatcher.
+    /// Includes allocated memory regardless of whether it currently has outstanding references.
+    ///
+    /// # Arguments
+    ///
+    /// * `thread_dispatcher_handle`: Thread dispatcher to check allocations for.
+    /// * `worker_index`: Index of the worker whose pool should be measured.
+    /// * `size`: Receives the total number of bytes allocated from native memory in the worker's pool.
+    ///
+    /// # Returns
+    ///
+    /// True if the size was computed, false if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "ThreadDispatcher.GetWorkerAllocatedMemorySize"]
+    pub fn get_worker_allocated_memory_size(
+        thread_dispatcher_handle: ThreadDispatcherHandle,
+        worker_index: i32,
+        size: *mut u64,
+    ) -> bool;
 }
@@ -0,0 +1,49 @@
+use std::{ffi::OsString, sync::OnceLock};
+
+use libloading::Library;
+
+/// Path the build script published the shared library to.
+const BUILT_LIBRARY_PATH: &str = env!("BEPUVY_LIBRARY_PATH");
+
+/// Gets the Bepuvy shared library, loading it on first use.
+///
+/// Setting `BEPUVY_LIBRARY_PATH` when running overrides the path the library was built at,
+/// so a rebuilt library can be swapped in without relinking.
+fn library() -> &'static Library {
+    static LIBRARY: OnceLock<Library> = OnceLock::new();
+    LIBRARY.get_or_init(|| {
+        let path = std::env::var_os("BEPUVY_LIBRARY_PATH")
+            .unwrap_or_else(|| OsString::from(BUILT_LIBRARY_PATH));
+        // SAFETY:
+        //
+        // The NativeAOT runtime in the library initializes itself lazily, so loading it runs no code that could conflict with ours.
+        unsafe { Library::new(&path) }.unwrap_or_else(|error| {
+            panic!(
+                "failed to load the Bepuvy library from {}: {error}",
+                path.to_string_lossy()
+            )
+        })
+    })
+}
+
+/// Resolves a function exported by the library.
+///
+/// `name` must be nul-terminated, and `F` must be the function pointer type matching the export's signature.
+///
+/// # Panics
+///
+/// Panics if the library can't be loaded or doesn't export `name`.
+pub(crate) fn resolve<F: Copy>(name: &str) -> F {
+    // SAFETY:
+    //
+    // Callers pair every export with the signature it was declared with on the C# side.
+    // The library is never unloaded, so the pointer outlives the borrowed symbol.
+    unsafe {
+        *library().get::<F>(name.as_bytes()).unwrap_or_else(|error| {
+            panic!(
+                "the Bepuvy library does not export {}: {error}",
+                name.trim_end_matches('\0')
+            )
+        })
+    }
+}
@@ -0,0 +1,28 @@
+native_functions! {
+    /// Gets the length in bytes of the UTF-8 encoded message of the last error recorded on this thread.
+    ///
+    /// # Returns
+    ///
+    /// Length of the message in bytes, or 0 if there is no error.
+    #[link_name = "Errors.GetLastErrorLength"]
+    pub fn get_last_error_length() -> i32;
+    /// Gets whether the last error recorded on this thread came from a failed handle check.
+    ///
+    /// # Returns
+    ///
+    /// True if the error is an invalid handle, false if it is any other error or there is no error.
+    #[link_name = "Errors.IsLastErrorInvalidHandle"]
+    pub fn is_last_error_invalid_handle() -> bool;
+    /// Copies the UTF-8 encoded message of the last error recorded on this thread and clears it.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer`: Buffer to copy the message into.
+    /// * `buffer_length`: Length of the buffer in bytes. Longer messages are truncated.
+    ///
+    /// # Returns
+    ///
+    /// Number of bytes written to the buffer.
+    #[link_name = "Errors.TakeLastError"]
+    pub fn take_last_error(buffer: *mut u8, buffer_length: i32) -> i32;
+}
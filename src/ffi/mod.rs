@@ -1,5 +1,57 @@
+/// Declares functions exported by the Bepuvy library.
+///
+/// Statically linked builds get a plain `extern "C"` block. With the `dynamic` feature, the block becomes a table of
+/// function pointers resolved from the shared library the first time any function of the module is called,
+/// along with `unsafe fn` wrappers of the same name and signature that call through it.
+macro_rules! native_functions {
+    ($(
+        $(#[doc = $doc:literal])*
+        #[link_name = $name:literal]
+        pub fn $function:ident($($arg:ident: $arg_ty:ty),* $(,)?) $(-> $ret:ty)?;
+    )*) => {
+        #[cfg(not(feature = "dynamic"))]
+        extern "C" {
+            $(
+                $(#[doc = $doc])*
+                #[link_name = $name]
+                pub fn $function($($arg: $arg_ty),*) $(-> $ret)?;
+            )*
+        }
+
+        // Like extern declarations, exports the safe API doesn't use yet shouldn't warn.
+        #[cfg(feature = "dynamic")]
+        #[allow(dead_code)]
+        struct NativeFunctions {
+            $($function: unsafe extern "C" fn($($arg_ty),*) $(-> $ret)?,)*
+        }
+
+        #[cfg(feature = "dynamic")]
+        fn native_functions() -> &'static NativeFunctions {
+            static FUNCTIONS: std::sync::OnceLock<NativeFunctions> = std::sync::OnceLock::new();
+            FUNCTIONS.get_or_init(|| NativeFunctions {
+                $($function: crate::ffi::dynamic::resolve(concat!($name, "\0")),)*
+            })
+        }
+
+        $(
+            #[cfg(feature = "dynamic")]
+            #[allow(dead_code)]
+            $(#[doc = $doc])*
+            pub unsafe fn $function($($arg: $arg_ty),*) $(-> $ret)? {
+                (native_functions().$function)($($arg),*)
+            }
+        )*
+    };
+}
+
 pub mod buffer_pool;
+pub mod constraints;
 pub mod dispatcher;
+#[cfg(feature = "dynamic")]
+mod dynamic;
+pub mod errors;
+pub mod queries;
 pub mod shapes;
 pub mod simulation;
 pub mod utils;
+pub mod validation;
@@ -0,0 +1,109 @@
+use crate::types::{
+    body::BodyVelocity,
+    handles::{SimulationHandle, TypedIndex},
+    math::scalar::{RigidPose, Vector3},
+    queries::{BroadPhaseOverlapHandler, RayHitHandler, SweepHitHandler},
+};
+
+native_functions! {
+    /// Casts a ray against every collidable in the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to cast the ray against.
+    /// * `origin`: Origin of the ray.
+    /// * `direction`: Direction of the ray. Hit distances are measured in units of this vector's length.
+    /// * `maximum_t`: Maximum distance along the ray to test.
+    /// * `handler`: Callbacks to filter collidables and receive hits.
+    ///
+    /// # Returns
+    ///
+    /// True if the query ran, false if it failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    ///
+    /// # Remarks
+    ///
+    /// Hits are reported in traversal order, not sorted by distance.
+    #[link_name = "Simulation.RayCast"]
+    pub fn ray_cast(
+        simulation_handle: SimulationHandle,
+        origin: Vector3,
+        direction: Vector3,
+        maximum_t: f32,
+        handler: *mut RayHitHandler,
+    ) -> bool;
+    /// Sweeps a convex shape against every collidable in the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to sweep the shape against.
+    /// * `shape_data`: Pointer to the shape to sweep.
+    /// * `shape_type`: Type id of the shape pointed to by `shape_data`. Must be a convex shape type.
+    /// * `pose`: Starting pose of the shape.
+    /// * `velocity`: Velocity of the shape over the sweep.
+    /// * `maximum_t`: Maximum time of impact to test.
+    /// * `handler`: Callbacks to filter collidables and receive hits.
+    ///
+    /// # Returns
+    ///
+    /// True if the query ran, false if it failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    ///
+    /// # Remarks
+    ///
+    /// Hits are reported in traversal order, not sorted by time of impact.
+    #[link_name = "Simulation.Sweep"]
+    pub fn sweep(
+        simulation_handle: SimulationHandle,
+        shape_data: *const (),
+        shape_type: i32,
+        pose: RigidPose,
+        velocity: BodyVelocity,
+        maximum_t: f32,
+        handler: *mut SweepHitHandler,
+    ) -> bool;
+    /// Sweeps a convex shape stored in the simulation against every collidable in the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to sweep the shape against.
+    /// * `shape`: Index of the shape to sweep. Must refer to a convex shape.
+    /// * `pose`: Starting pose of the shape.
+    /// * `velocity`: Velocity of the shape over the sweep.
+    /// * `maximum_t`: Maximum time of impact to test.
+    /// * `handler`: Callbacks to filter collidables and receive hits.
+    ///
+    /// # Returns
+    ///
+    /// True if the query ran, false if it failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    ///
+    /// # Remarks
+    ///
+    /// Hits are reported in traversal order, not sorted by time of impact.
+    #[link_name = "Simulation.SweepIndexed"]
+    pub fn sweep_indexed(
+        simulation_handle: SimulationHandle,
+        shape: TypedIndex,
+        pose: RigidPose,
+        velocity: BodyVelocity,
+        maximum_t: f32,
+        handler: *mut SweepHitHandler,
+    ) -> bool;
+    /// Finds every collidable whose broad-phase bounds overlap a box, covering both the active and static trees.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to query.
+    /// * `min`: Minimum corner of the query box.
+    /// * `max`: Maximum corner of the query box.
+    /// * `handler`: Callbacks to receive overlapping collidables.
+    ///
+    /// # Returns
+    ///
+    /// True if the query ran, false if it failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.QueryAabb"]
+    pub fn query_aabb(
+        simulation_handle: SimulationHandle,
+        min: Vector3,
+        max: Vector3,
+        handler: *mut BroadPhaseOverlapHandler,
+    ) -> bool;
+}
@@ -6,13 +6,17 @@ use crate::types::{
     utilities::Buffer,
 };
 
-extern "C" {
+native_functions! {
     /// Adds a sphere shape to the simulation.
     ///
     /// # Arguments
     ///
     /// * `simulation_handle`: Handle of the simulation to add the shape to.
     /// * `sphere`: Shape to add to the simulation.
+    ///
+    /// # Returns
+    ///
+    /// Index of the added shape, or an index that refers to no shape if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.AddSphere"]
     pub fn add_sphere(simulation_handle: SimulationHandle, sphere: Sphere) -> TypedIndex;
     /// Adds a capsule shape to the simulation.
@@ -21,6 +25,10 @@ extern "C" {
     ///
     /// * `simulation_handle`: Handle of the simulation to add the shape to.
     /// * `capsule`: Shape to add to the simulation.
+    ///
+    /// # Returns
+    ///
+    /// Index of the added shape, or an index that refers to no shape if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.AddCapsule"]
     pub fn add_capsule(simulation_handle: SimulationHandle, capsule: Capsule) -> TypedIndex;
     /// Adds a box shape to the simulation.
@@ -29,6 +37,10 @@ extern "C" {
     ///
     /// * `simulation_handle`: Handle of the simulation to add the shape to.
     /// * `box`: Shape to add to the simulation.
+    ///
+    /// # Returns
+    ///
+    /// Index of the added shape, or an index that refers to no shape if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.AddBox"]
     pub fn add_box(simulation_handle: SimulationHandle, box_: Box) -> TypedIndex;
     /// Adds a triangle shape to the simulation.
@@ -37,6 +49,10 @@ extern "C" {
     ///
     /// * `simulation_handle`: Handle of the simulation to add the shape to.
     /// * `triangle`: Shape to add to the simulation.
+    ///
+    /// # Returns
+    ///
+    /// Index of the added shape, or an index that refers to no shape if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.AddTriangle"]
     pub fn add_triangle(simulation_handle: SimulationHandle, triangle: Triangle) -> TypedIndex;
     /// Adds a cylinder shape to the simulation.
@@ -45,6 +61,10 @@ extern "C" {
     ///
     /// * `simulation_handle`: Handle of the simulation to add the shape to.
     /// * `cylinder`: Shape to add to the simulation.
+    ///
+    /// # Returns
+    ///
+    /// Index of the added shape, or an index that refers to no shape if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.AddCylinder"]
     pub fn add_cylinder(simulation_handle: SimulationHandle, cylinder: Cylinder) -> TypedIndex;
     /// Adds a convex hull shape to the simulation.
@@ -53,6 +73,10 @@ extern "C" {
     ///
     /// * `simulation_handle`: Handle of the simulation to add the shape to.
     /// * `convex_hull`: Shape to add to the simulation.
+    ///
+    /// # Returns
+    ///
+    /// Index of the added shape, or an index that refers to no shape if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.AddConvexHull"]
     pub fn add_convex_hull(
         simulation_handle: SimulationHandle,
@@ -64,6 +88,10 @@ extern "C" {
     ///
     /// * `simulation_handle`: Handle of the simulation to add the shape to.
     /// * `big_compound`: Shape to add to the simulation.
+    ///
+    /// # Returns
+    ///
+    /// Index of the added shape, or an index that refers to no shape if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.AddCompound"]
     pub fn add_compound(simulation_handle: SimulationHandle, big_compound: Compound) -> TypedIndex;
     /// Adds a big compound shape to the simulation.
@@ -72,6 +100,10 @@ extern "C" {
     ///
     /// * `simulation_handle`: Handle of the simulation to add the shape to.
     /// * `big_compound`: Shape to add to the simulation.
+    ///
+    /// # Returns
+    ///
+    /// Index of the added shape, or an index that refers to no shape if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.AddBigCompound"]
     pub fn add_big_compound(
         simulation_handle: SimulationHandle,
@@ -83,6 +115,10 @@ extern "C" {
     ///
     /// * `simulation_handle`: Handle of the simulation to add the shape to.
     /// * `mesh`: Shape to add to the simulation.
+    ///
+    /// # Returns
+    ///
+    /// Index of the added shape, or an index that refers to no shape if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.AddMesh"]
     pub fn add_mesh(simulation_handle: SimulationHandle, mesh: Mesh) -> TypedIndex;
     /// Removes a shape from the simulation. Does not return any shape allocated buffers to buffer pools.
@@ -91,8 +127,12 @@ extern "C" {
     ///
     /// * `simulation_handle`: Handle of the simulation to remove the shape from.
     /// * `shape`: Shape to remove from the simulation.
+    ///
+    /// # Returns
+    ///
+    /// True if the shape was removed, false if removing failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.RemoveShape"]
-    pub fn remove_shape(simulation_handle: SimulationHandle, shape: TypedIndex);
+    pub fn remove_shape(simulation_handle: SimulationHandle, shape: TypedIndex) -> bool;
     /// Removes a shape from the simulation. If the shape has resources that were allocated from a buffer pool, they will be returned to the specified pool.
     ///
     /// # Arguments
@@ -104,12 +144,16 @@ extern "C" {
     /// # Remarks
     ///
     /// The same buffer pool must be used for both allocation and deallocation.
+    ///
+    /// # Returns
+    ///
+    /// True if the shape was removed, false if removing failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.RemoveAndDestroyShape"]
     pub fn remove_and_destroy_shape(
         simulation_handle: SimulationHandle,
         buffer_pool_handle: BufferPoolHandle,
         shape: TypedIndex,
-    );
+    ) -> bool;
     /// Removes a shape and all references child shapes from the simulation. If the shapes had resources that were allocated from a buffer pool, they will be returned to the specified pool.
     ///
     /// # Arguments
@@ -121,12 +165,16 @@ extern "C" {
     /// # Remarks
     ///
     /// The same buffer pool must be used for both allocation and deallocation.
+    ///
+    /// # Returns
+    ///
+    /// True if the shapes were removed, false if removing failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.RemoveAndDestroyShapeRecursively"]
     pub fn remove_and_destroy_shape_recursively(
         simulation_handle: SimulationHandle,
         buffer_pool_handle: BufferPoolHandle,
         shape: TypedIndex,
-    );
+    ) -> bool;
     /// Creates a convex hull shape from a point set.
     ///
     /// # Arguments
@@ -134,28 +182,45 @@ extern "C" {
     /// * `buffer_pool_handle`: Buffer pool to allocate resources from for the compound's acceleration structures.
     /// * `points`: Points in the convex hull.
     /// * `center_of_mass`: Center of mass computed for the hull and subtracted from all the points in the points used for the final shape.
+    /// * `hull`: Receives the created hull.
+    ///
+    /// # Returns
+    ///
+    /// Whether the hull was created. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.CreateConvexHull"]
     pub fn create_convex_hull(
         buffer_pool_handle: BufferPoolHandle,
         points: Buffer<Vector3>,
         center_of_mass: *mut Vector3,
-    ) -> ConvexHull;
+        hull: *mut ConvexHull,
+    ) -> bool;
     /// Returns buffers allocated for a convex hull shape.
     ///
     /// # Arguments
     ///
     /// * `buffer_pool_handle`: Buffer pool to return resources to. Must be the same pool that resources were allocated from.
     /// * `convex_hull`: Convex hull to destroy.
+    ///
+    /// # Returns
+    ///
+    /// True if the hull's buffers were returned, false if returning failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.DestroyConvexHull"]
-    pub fn destroy_convex_hull(buffer_pool_handle: BufferPoolHandle, convex_hull: *mut ConvexHull);
+    pub fn destroy_convex_hull(
+        buffer_pool_handle: BufferPoolHandle,
+        convex_hull: *mut ConvexHull,
+    ) -> bool;
     /// Returns buffers allocated for a compound shape.
     ///
     /// # Arguments
     ///
     /// * `buffer_pool_handle`: Buffer pool to return resources to. Must be the same pool that resources were allocated from.
     /// * `compound`: Compound to destroy.
+    ///
+    /// # Returns
+    ///
+    /// True if the compound's buffers were returned, false if returning failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.DestroyCompound"]
-    pub fn destroy_compound(buffer_pool_handle: BufferPoolHandle, compound: *mut Compound);
+    pub fn destroy_compound(buffer_pool_handle: BufferPoolHandle, compound: *mut Compound) -> bool;
     /// Creates a big compound shape from a list of children.
     ///
     /// # Arguments
@@ -163,23 +228,33 @@ extern "C" {
     /// * `simulation_handle`: Handle of the simulation to which the shapes referenced by the compound children belong.
     /// * `buffer_pool_handle`: Buffer pool to allocate resources from for the compound's acceleration structures.
     /// * `children`: Children of the compound.
+    /// * `big_compound`: Receives the created compound.
+    ///
+    /// # Returns
+    ///
+    /// Whether the compound was created. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.CreateBigCompound"]
     pub fn create_big_compound(
         simulation_handle: SimulationHandle,
         buffer_pool_handle: BufferPoolHandle,
         children: Buffer<CompoundChild>,
-    ) -> BigCompound;
+        big_compound: *mut BigCompound,
+    ) -> bool;
     /// Returns buffers allocated for a big compound shape.
     ///
     /// # Arguments
     ///
     /// * `buffer_pool_handle`: Buffer pool to return resources to. Must be the same pool that resources were allocated from.
     /// * `big_compound`: Big compound to destroy.
+    ///
+    /// # Returns
+    ///
+    /// True if the compound's buffers were returned, false if returning failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.DestroyBigCompound"]
     pub fn destroy_big_compound(
         buffer_pool_handle: BufferPoolHandle,
         big_compound: *mut BigCompound,
-    );
+    ) -> bool;
 
     /// Creates a mesh shape from triangles.
     ///
@@ -188,6 +263,11 @@ extern "C" {
     /// * `buffer_pool_handle`: Buffer pool to allocate resources from for the compound's acceleration structures.
     /// * `triangles`: Triangles composing the mesh.
     /// * `scale`: Scale of the mesh.
+    /// * `mesh`: Receives the created mesh.
+    ///
+    /// # Returns
+    ///
+    /// Whether the mesh was created. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     ///
     /// # Remarks
     ///
@@ -197,15 +277,20 @@ extern "C" {
         buffer_pool_handle: BufferPoolHandle,
         triangles: Buffer<Triangle>,
         scale: Vector3,
-    ) -> Mesh;
+        mesh: *mut Mesh,
+    ) -> bool;
     /// Returns buffers allocated for a mesh shape.
     ///
     /// # Arguments
     ///
     /// * `buffer_pool_handle`: Buffer pool to return resources to. Must be the same pool that resources were allocated from.
     /// * `mesh`: Mesh to destroy.
+    ///
+    /// # Returns
+    ///
+    /// True if the mesh's buffers were returned, false if returning failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Mesh.Destroy"]
-    pub fn destroy_mesh(buffer_pool_handle: BufferPoolHandle, mesh: *mut Mesh);
+    pub fn destroy_mesh(buffer_pool_handle: BufferPoolHandle, mesh: *mut Mesh) -> bool;
     /// Computes the inertia of a sphere.
     ///
     /// # Arguments
@@ -284,16 +369,18 @@ extern "C" {
     ///
     /// * `convex`: Index of a convex to calculate the inertia for.
     /// * `mass`: Mass to use in the inertia calculation.
+    /// * `inertia`: Receives the inertia of the shape. If the shape index was not a convex, this receives a zeroed inverse inertia tensor.
     ///
     /// # Returns
     ///
-    /// Inertia of the shape. If the shape index was not a convex, this returns a zeroed inverse inertia tensor.
+    /// True if the inertia was computed, false if computing failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.ComputeConvexInertia"]
     pub fn compute_convex_inertia(
         simulation_handle: SimulationHandle,
         convex: TypedIndex,
         mass: f32,
-    ) -> BodyInertia;
+        inertia: *mut BodyInertia,
+    ) -> bool;
     /// Computes the inertia associated with a set of compound children. Does not recenter the children.
     ///
     /// # Arguments
@@ -301,12 +388,18 @@ extern "C" {
     /// * `simulation_handle`: Handle of the simulation to which the shapes referenced by the compound children belong.
     /// * `children`: Children of the compound.
     /// * `child_masses`: Masses of the children composing the compound.
+    /// * `inertia`: Receives the computed inertia of the compound.
+    ///
+    /// # Returns
+    ///
+    /// True if the inertia was computed, false if computing failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.ComputeCompoundInertia"]
     pub fn compute_compound_inertia(
         simulation_handle: SimulationHandle,
         children: Buffer<CompoundChild>,
         child_masses: Buffer<f32>,
-    ) -> BodyInertia;
+        inertia: *mut BodyInertia,
+    ) -> bool;
     /// Computes the inertia associated with a set of compound children. Recenters all children onto the computed local center of mass.
     ///
     /// # Arguments
@@ -315,13 +408,19 @@ extern "C" {
     /// * `children`: Children of the compound.
     /// * `child_masses`: Masses of the children composing the compound.
     /// * `center_of_mass`: Computed center of mass that was subtracted from the position of compound children.
+    /// * `inertia`: Receives the computed inertia of the compound.
+    ///
+    /// # Returns
+    ///
+    /// True if the inertia was computed, false if computing failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.ComputeCompoundInertiaWithRecentering"]
     pub fn compute_compound_inertia_with_recentering(
         simulation_handle: SimulationHandle,
         children: Buffer<CompoundChild>,
         child_masses: Buffer<f32>,
         center_of_mass: *mut Vector3,
-    ) -> BodyInertia;
+        inertia: *mut BodyInertia,
+    ) -> bool;
     /// Computes the inertia associated with a mesh by treating its triangles as a soup with no volume. Does not recenter the triangles on a computed center of mass.
     ///
     /// # Arguments
@@ -371,7 +470,7 @@ extern "C" {
     ///
     /// # Returns
     ///
-    /// Pointer to the shape's data in the simulation's shapes buffers.
+    /// Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.GetSphereData"]
     pub fn get_sphere_shape_data(
         simulation_handle: SimulationHandle,
@@ -386,7 +485,7 @@ extern "C" {
     ///
     /// # Returns
     ///
-    /// Pointer to the shape's data in the simulation's shapes buffers.
+    /// Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.GetCapsuleData"]
     pub fn get_capsule_shape_data(
         simulation_handle: SimulationHandle,
@@ -401,7 +500,7 @@ extern "C" {
     ///
     /// # Returns
     ///
-    /// Pointer to the shape's data in the simulation's shapes buffers.
+    /// Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.GetBoxData"]
     pub fn get_box_shape_data(simulation_handle: SimulationHandle, shape: TypedIndex) -> *mut Box;
     /// Gets a pointer to a triangle shape's data stored within the simulation's shapes buffers.
@@ -413,7 +512,7 @@ extern "C" {
     ///
     /// # Returns
     ///
-    /// Pointer to the shape's data in the simulation's shapes buffers.
+    /// Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.GetTriangleData"]
     pub fn get_triangle_shape_data(
         simulation_handle: SimulationHandle,
@@ -428,7 +527,7 @@ extern "C" {
     ///
     /// # Returns
     ///
-    /// Pointer to the shape's data in the simulation's shapes buffers.
+    /// Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.GetCylinderData"]
     pub fn get_cylinder_shape_data(
         simulation_handle: SimulationHandle,
@@ -443,7 +542,7 @@ extern "C" {
     ///
     /// # Returns
     ///
-    /// Pointer to the shape's data in the simulation's shapes buffers.
+    /// Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.GetConvexHullData"]
     pub fn get_convex_hull_shape_data(
         simulation_handle: SimulationHandle,
@@ -458,7 +557,7 @@ extern "C" {
     ///
     /// # Returns
     ///
-    /// Pointer to the shape's data in the simulation's shapes buffers.
+    /// Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.GetCompoundData"]
     pub fn get_compound_shape_data(
         simulation_handle: SimulationHandle,
@@ -473,7 +572,7 @@ extern "C" {
     ///
     /// # Returns
     ///
-    /// Pointer to the shape's data in the simulation's shapes buffers.
+    /// Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.GetBigCompoundData"]
     pub fn get_big_compound_shape_data(
         simulation_handle: SimulationHandle,
@@ -488,7 +587,7 @@ extern "C" {
     ///
     /// # Returns
     ///
-    /// Pointer to the shape's data in the simulation's shapes buffers.
+    /// Pointer to the shape's data in the simulation's shapes buffers, or null if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Shapes.GetMeshData"]
     pub fn get_mesh_shape_data(simulation_handle: SimulationHandle, shape: TypedIndex)
         -> *mut Mesh;
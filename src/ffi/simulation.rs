@@ -8,7 +8,7 @@ use crate::types::{
     utilities::{Buffer, QuickList},
 };
 
-extern "C" {
+native_functions! {
     /// Creates a new simulation.
     ///
     /// # Arguments
@@ -16,29 +16,60 @@ extern "C" {
     /// * `buffer_pool`: Buffer pool for the simulation's main allocations.
     /// * `narrow_phase_callbacks`: Narrow phase callbacks to be invoked by the simulation.
     /// * `pose_integrator_callbacks`: Pose integration state and callbacks to be invoked by the simulation.
+    /// * `callback`: Opaque pointer passed to the pose integrator callbacks. Must stay valid until the simulation is destroyed.
     /// * `solve_description_interop`: Defines velocity iteration count and substep counts for the simulation's solver.
     /// * `initial_allocation_sizes`: Initial capacities to allocate within the simulation.
     ///
     /// # Returns
     ///
-    /// The created simulation.
+    /// The created simulation, or a null handle if creation failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Create"]
     pub fn create_simulation(
         buffer_pool: BufferPoolHandle,
         narrow_phase_callbacks: NarrowPhaseCallbacks,
         pose_integrator_callbacks: PoseIntegratorCallbacks,
+        callback: *mut (),
         solve_description_interop: SolveDescription,
         initial_allocation_sizes: SimulationAllocationSizes,
     ) -> SimulationHandle;
+    /// Destroys a simulation and invalidates its handle.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle`: Simulation to destroy.
+    ///
+    /// # Returns
+    ///
+    /// True if the simulation was destroyed, false if destroying failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Destroy"]
-    pub fn destroy_simulation(handle: SimulationHandle);
+    pub fn destroy_simulation(handle: SimulationHandle) -> bool;
+    /// Adds a body to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the body to.
+    /// * `body_description`: Description of the body to add.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created body, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.AddBody"]
     pub fn add_body(
         simulation_handle: SimulationHandle,
         body_description: BodyDescription,
     ) -> BodyHandle;
+    /// Removes a body from the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to remove the body from.
+    /// * `body_handle`: Body to remove.
+    ///
+    /// # Returns
+    ///
+    /// True if the body was removed, false if removing failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.RemoveBody"]
-    pub fn remove_body(simulation_handle: SimulationHandle, body_handle: BodyHandle);
+    pub fn remove_body(simulation_handle: SimulationHandle, body_handle: BodyHandle) -> bool;
     /// Gets a pointer to the dynamic state associated with a body. Includes pose, velocity, and inertia.
     ///
     /// # Arguments
@@ -48,7 +79,7 @@ extern "C" {
     ///
     /// # Returns
     ///
-    /// Pointer to the body's dynamic state.
+    /// Pointer to the body's dynamic state, or null if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     ///
     /// # Remarks
     ///
@@ -67,7 +98,7 @@ extern "C" {
     ///
     /// # Returns
     ///
-    /// Pointer to the body's collidable.
+    /// Pointer to the body's collidable, or null if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     ///
     /// # Remarks
     ///
@@ -86,7 +117,7 @@ extern "C" {
     ///
     /// # Returns
     ///
-    /// Pointer to the body's activity state.
+    /// Pointer to the body's activity state, or null if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     ///
     /// # Remarks
     ///
@@ -105,7 +136,7 @@ extern "C" {
     ///
     /// # Returns
     ///
-    /// Pointer to the body's constraint list.
+    /// Pointer to the body's constraint list, or null if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     ///
     /// # Remarks
     ///
@@ -121,15 +152,17 @@ extern "C" {
     ///
     /// * `simulation_handle`: Simulation to pull a body's state from.
     /// * `body_handle`: Body handle to pull data about.
+    /// * `description`: Receives the description of the body.
     ///
     /// # Returns
     ///
-    /// Description of a body.
+    /// True if the description was read, false if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.GetBodyDescription"]
     pub fn get_body_description(
         simulation_handle: SimulationHandle,
         body_handle: BodyHandle,
-    ) -> BodyDescription;
+        description: *mut BodyDescription,
+    ) -> bool;
     /// Applies a description to a body.
     ///
     /// # Arguments
@@ -137,19 +170,60 @@ extern "C" {
     /// * `simulation_handle`: Simulation to pull a body's state from.
     /// * `body_handle`: Body handle to pull data about.
     /// * `description`: Description to apply to the body.
+    ///
+    /// # Returns
+    ///
+    /// True if the description was applied, false if applying failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.ApplyBodyDescription"]
     pub fn apply_body_description(
         simulation_handle: SimulationHandle,
         body_handle: BodyHandle,
         description: BodyDescription,
-    );
+    ) -> bool;
+    /// Sets the local inertia of a body, waking it and moving it between the kinematic and dynamic states if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation containing the body.
+    /// * `body_handle`: Body handle to set the inertia of.
+    /// * `local_inertia`: New inertia of the body in its local space.
+    ///
+    /// # Returns
+    ///
+    /// True if the inertia was set, false if setting failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    #[link_name = "Simulation.SetBodyLocalInertia"]
+    pub fn set_body_local_inertia(
+        simulation_handle: SimulationHandle,
+        body_handle: BodyHandle,
+        local_inertia: BodyInertia,
+    ) -> bool;
+    /// Adds a static to the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to add the static to.
+    /// * `static_description`: Description of the static to add.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the created static, or a negative handle if adding failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.AddStatic"]
     pub fn add_static(
         simulation_handle: SimulationHandle,
         static_description: StaticDescription,
     ) -> StaticHandle;
+    /// Removes a static from the simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation_handle`: Simulation to remove the static from.
+    /// * `static_handle`: Static to remove.
+    ///
+    /// # Returns
+    ///
+    /// True if the static was removed, false if removing failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.RemoveStatic"]
-    pub fn remove_static(simulation_handle: SimulationHandle, static_handle: StaticHandle);
+    pub fn remove_static(simulation_handle: SimulationHandle, static_handle: StaticHandle) -> bool;
     /// Gets a pointer to data associated with a static.
     ///
     /// # Arguments
@@ -159,7 +233,7 @@ extern "C" {
     ///
     /// # Returns
     ///
-    /// Pointer to the static's data.
+    /// Pointer to the static's data, or null if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     ///
     /// # Remarks
     ///
@@ -175,27 +249,34 @@ extern "C" {
     ///
     /// * `simulation_handle`: Simulation to pull a static's state from.
     /// * `static_handle`: Static handle to pull data about.
+    /// * `description`: Receives the description of the static.
     ///
     /// # Returns
     ///
-    /// Description of the static..
+    /// True if the description was read, false if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.GetStaticDescription"]
     pub fn get_static_description(
         simulation_handle: SimulationHandle,
         static_handle: StaticHandle,
-    ) -> StaticDescription;
+        description: *mut StaticDescription,
+    ) -> bool;
     /// Applies a description to a static.
     ///
     /// # Arguments
     ///
     /// * `simulation_handle`: Simulation to pull a static's state from.
     /// * `static_handle`: Static handle to pull data about.
+    /// * `description`: Description to apply to the static.
+    ///
+    /// # Returns
+    ///
+    /// True if the description was applied, false if applying failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.ApplyStaticDescription"]
     pub fn apply_static_description(
         simulation_handle: SimulationHandle,
         static_handle: StaticHandle,
         description: StaticDescription,
-    );
+    ) -> bool;
 
     /// Steps the simulation forward a single time.
     ///
@@ -204,13 +285,16 @@ extern "C" {
     /// * `simulation_handle`: Handle of the simulation to step.
     /// * `dt`: Duration of the timestep.
     /// * `thread_dispatcher_handle`: Handle of the thread dispatcher to use, if any. Can be a null reference.
+    ///
+    /// # Returns
+    ///
+    /// Whether the step completed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.Timestep"]
     pub fn timestep(
         simulation_handle: SimulationHandle,
         dt: f32,
-        callback: *mut (),
         thread_dispatcher_handle: ThreadDispatcherHandle,
-    );
+    ) -> bool;
     /// Grabs a collidable's bounding boxes in the broad phase.
     ///
     /// # Arguments
@@ -219,13 +303,17 @@ extern "C" {
     /// * `body_handle`: Body to pull bounding box data about.
     /// * `min`: Minimum bounds of the collidable's bounding box.
     /// * `max`: Maximum bounds of the collidable's bounding box.
+    ///
+    /// # Returns
+    ///
+    /// True if the bounds were read, false if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.GetBodyBoundingBoxInBroadPhase"]
     pub fn get_body_bounding_box_in_broad_phase(
         simulation_handle: SimulationHandle,
         body_handle: BodyHandle,
         min: *mut crate::types::math::scalar::Vector3,
         max: *mut Vector3,
-    );
+    ) -> bool;
     /// Grabs a collidable's bounding boxes in the broad phase.
     ///
     /// # Arguments
@@ -234,13 +322,17 @@ extern "C" {
     /// * `static_handle`: Static to pull bounding box data about.
     /// * `min`: Minimum bounds of the collidable's bounding box.
     /// * `max`: Maximum bounds of the collidable's bounding box.
+    ///
+    /// # Returns
+    ///
+    /// True if the bounds were read, false if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
     #[link_name = "Simulation.GetStaticBoundingBoxInBroadPhase"]
     pub fn get_static_bounding_box_in_broad_phase(
         simulation_handle: SimulationHandle,
         static_handle: StaticHandle,
         min: *mut Vector3,
         max: *mut Vector3,
-    );
+    ) -> bool;
     /// Gets the mapping from body handles to the body's location in storage.
     ///
     /// # Arguments
@@ -248,6 +340,10 @@ extern "C" {
     /// * `simulation_handle`: Handle of the simulation to pull data from.
     /// * `body_handle_to_index_mapping`: Mapping from a body handle to the body's memory location.
     ///
+    /// # Returns
+    ///
+    /// True if the mapping was read, false if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    ///
     /// # Remarks
     ///
     /// The buffer returned by this function can be invalidated if the simulation resizes it.
@@ -255,7 +351,7 @@ extern "C" {
     pub fn get_body_handle_to_location_mapping(
         simulation_handle: SimulationHandle,
         body_handle_to_index_mapping: *mut Buffer<BodyMemoryLocation>,
-    );
+    ) -> bool;
     /// Gets the body sets for a simulation. Slot 0 is the active set. Subsequent sets are sleeping. Not every slot beyond slot 0 is filled.
     ///
     /// # Arguments
@@ -263,11 +359,18 @@ extern "C" {
     /// * `simulation_handle`: Handle of the simulation to pull data from.
     /// * `body_sets`: Mapping from a body handle to the body's memory location.
     ///
+    /// # Returns
+    ///
+    /// True if the body sets were read, false if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    ///
     /// # Remarks
     ///
     /// The buffer returned by this function can be invalidated if the simulation resizes it.
     #[link_name = "Simulation.GetBodySets"]
-    pub fn get_body_sets(simulation_handle: SimulationHandle, body_sets: *mut Buffer<BodySet>);
+    pub fn get_body_sets(
+        simulation_handle: SimulationHandle,
+        body_sets: *mut Buffer<BodySet>,
+    ) -> bool;
     /// Gets the mapping from body handles to the body's location in storage.
     ///
     /// # Arguments
@@ -275,6 +378,10 @@ extern "C" {
     /// * `simulation_handle`: Handle of the simulation to pull data from.
     /// * `static_handle_to_index_mapping`: Mapping from a static handle to the static's memory location.
     ///
+    /// # Returns
+    ///
+    /// True if the mapping was read, false if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    ///
     /// # Remarks
     ///
     /// The buffer returned by this function can be invalidated if the simulation resizes it.
@@ -282,7 +389,7 @@ extern "C" {
     pub fn get_static_handle_to_location_mapping(
         simulation_handle: SimulationHandle,
         static_handle_to_index_mapping: *mut Buffer<i32>,
-    );
+    ) -> bool;
     /// Gets the statics set for a simulation.
     ///
     /// # Arguments
@@ -291,6 +398,10 @@ extern "C" {
     /// * `statics`: The set of all statics within a simulation.
     /// * `count`: Number of statics in the simulation.
     ///
+    /// # Returns
+    ///
+    /// True if the statics were read, false if the lookup failed. On failure the error can be retrieved with `crate::ffi::errors::take_last_error`.
+    ///
     /// # Remarks
     ///
     /// The buffer returned by this function can be invalidated if the simulation resizes it. The count is a snapshot.
@@ -299,5 +410,5 @@ extern "C" {
         simulation_handle: SimulationHandle,
         statics: *mut Buffer<Static>,
         count: *mut i32,
-    );
+    ) -> bool;
 }
@@ -1,4 +1,4 @@
-extern "C" {
+native_functions! {
     #[link_name = "Utils.GetSIMDWidth"]
     pub fn get_simd_width() -> u32;
     /// Gets the number of threads exposed by the operating system on this platform. Cores with SMT can show as having multiple threads.
@@ -0,0 +1,19 @@
+use crate::types::handles::{SimulationHandle, TypedIndex};
+
+native_functions! {
+    /// Gets whether the library was built with handle checks in its entrypoints.
+    #[link_name = "Validation.IsEnabled"]
+    pub fn is_validation_enabled() -> bool;
+    /// Checks whether a shape index can be used with a simulation. Available whether or not the library was built with handle checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation`: Simulation the shape is expected to live in.
+    /// * `shape`: Shape index to check.
+    ///
+    /// # Returns
+    ///
+    /// True if the index points into a registered shape batch, false if it doesn't and an error was recorded.
+    #[link_name = "Validation.ValidateShape"]
+    pub fn validate_shape(simulation: SimulationHandle, shape: TypedIndex) -> bool;
+}
@@ -1,11 +1,16 @@
 #![feature(portable_simd)]
 
 pub mod bepu;
+pub mod error;
 pub(crate) mod ffi;
-pub(crate) mod types;
+pub mod types;
+
+pub use bepu::memory::runtime_memory;
+pub use error::Error;
 
 pub mod prelude {
     pub use crate::bepu::{
-        buffer_pool::BufferPool, dispatcher::ThreadDispatcher, simulation::Simulation,
+        buffer_pool::BufferPool, dispatcher::ThreadDispatcher, memory::MemoryReport,
+        simulation::{Simulation, SimulationBuilder},
     };
 }
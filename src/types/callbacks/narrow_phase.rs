@@ -8,13 +8,17 @@ pub struct NarrowPhaseCallbacks {
     /// # Arguments
     ///
     /// * `simulation_handle`: Handle of the simulation owning these callbacks.
-    pub initialize_function: Option<unsafe extern "C" fn(simulation_handle: SimulationHandle)>,
+    /// * `context`: Context pointer stored in [`NarrowPhaseCallbacks::context`].
+    pub initialize_function:
+        Option<unsafe extern "C" fn(simulation_handle: SimulationHandle, context: *mut ())>,
     /// Called when the simulation is being torn down. Can be null.
     ///
     /// # Arguments
     ///
     /// * `simulation_handle`: Handle of the simulation owning these callbacks.
-    pub dispose_function: Option<unsafe extern "C" fn(simulation_handle: SimulationHandle)>,
+    /// * `context`: Context pointer stored in [`NarrowPhaseCallbacks::context`].
+    pub dispose_function:
+        Option<unsafe extern "C" fn(simulation_handle: SimulationHandle, context: *mut ())>,
     /// Called for each pair of collidables with overlapping bounding boxes found by the broad phase.
     ///
     /// # Arguments
@@ -24,6 +28,7 @@ pub struct NarrowPhaseCallbacks {
     /// * `a`: First collidable in the pair.
     /// * `b`: Second collidable in the pair.
     /// * `speculative_margin`: Speculative contact margin for the pair. Calculated ahead of time, but can be overridden.
+    /// * `context`: Context pointer stored in [`NarrowPhaseCallbacks::context`].
     ///
     /// returns: True if the collision detection should run for this pair, false otherwise.
     pub allow_contact_generation_function: Option<
@@ -33,6 +38,7 @@ pub struct NarrowPhaseCallbacks {
             a: CollidableReference,
             b: CollidableReference,
             speculative_margin: *mut f32,
+            context: *mut (),
         ) -> bool,
     >,
     /// For pairs involving compound collidables (any type that has children, e.g. Compound, BigCompound, and Mesh), this is invoked for each pair of children with overlapping bounds.
@@ -44,6 +50,7 @@ pub struct NarrowPhaseCallbacks {
     /// * `collidable_pair`: References to the parent collidables in this pair.
     /// * `child_index_a`: Index of the child belonging to the first collidable in the pair.
     /// * `child_index_b`: Index of the child belonging to the second collidable in the pair.
+    /// * `context`: Context pointer stored in [`NarrowPhaseCallbacks::context`].
     ///
     /// returns: True if the collision detection should run for these children, false otherwise.
     pub allow_contact_generation_between_children_function: Option<
@@ -53,6 +60,7 @@ pub struct NarrowPhaseCallbacks {
             collidable_pair: CollidablePair,
             child_index_a: i32,
             child_index_b: i32,
+            context: *mut (),
         ) -> bool,
     >,
     /// Called after contacts have been found for a collidable pair that resulted in a convex manifold.
@@ -64,6 +72,7 @@ pub struct NarrowPhaseCallbacks {
     /// * `collidable_pair`: References to the parent collidables in this pair.
     /// * `contact_manifold`: Contacts identified between the pair.
     /// * `material_properties`: Contact constraint material properties to use for the constraint, if any.
+    /// * `context`: Context pointer stored in [`NarrowPhaseCallbacks::context`].
     ///
     /// returns: True if a contact constraint should be created for this contact manifold, false otherwise.
     pub configure_convex_contact_manifold_function: Option<
@@ -73,6 +82,7 @@ pub struct NarrowPhaseCallbacks {
             collidable_pair: CollidablePair,
             contact_manifold: *mut ConvexContactManifold,
             material_properties: *mut PairMaterialProperties,
+            context: *mut (),
         ) -> bool,
     >,
     /// Called after contacts have been found for a collidable pair that resulted in a nonconvex manifold.
@@ -84,6 +94,7 @@ pub struct NarrowPhaseCallbacks {
     /// * `collidable_pair`: References to the parent collidables in this pair.
     /// * `contact_manifold`: Contacts identified between the pair.
     /// * `material_properties`: Contact constraint material properties to use for the constraint, if any.
+    /// * `context`: Context pointer stored in [`NarrowPhaseCallbacks::context`].
     ///
     /// returns: True if a contact constraint should be created for this contact manifold, false otherwise.
     pub configure_nonconvex_contact_manifold_function: Option<
@@ -93,6 +104,7 @@ pub struct NarrowPhaseCallbacks {
             collidable_pair: CollidablePair,
             contact_manifold: *mut NonconvexContactManifold,
             material_properties: *mut PairMaterialProperties,
+            context: *mut (),
         ) -> bool,
     >,
     /// Called for contacts identified between children in a compound-involving pair prior to being processed into the top level contact manifold.
@@ -105,6 +117,7 @@ pub struct NarrowPhaseCallbacks {
     /// * `child_index_a`: Index of the child belonging to the first collidable in the pair.
     /// * `child_index_b`: Index of the child belonging to the second collidable in the pair.
     /// * `contact_manifold`: Contacts identified between the pair.
+    /// * `context`: Context pointer stored in [`NarrowPhaseCallbacks::context`].
     ///
     /// returns: True if the contacts in this child pair should be considered for constraint generation, false otherwise.
     ///
@@ -119,6 +132,9 @@ pub struct NarrowPhaseCallbacks {
             child_index_a: i32,
             child_index_b: i32,
             contact_manifold: *mut ConvexContactManifold,
+            context: *mut (),
         ) -> bool,
     >,
+    /// Opaque pointer passed to every callback. Can be null.
+    pub context: *mut (),
 }
@@ -10,7 +10,7 @@ use crate::types::WIDEST_LANE;
 
 /// Defines how a pose integrator should handle angular velocity integration.
 #[repr(C)]
-#[derive(PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AngularIntegrationMode {
     /// Angular velocity is directly integrated and does not change as the body pose changes. Does not conserve angular momentum.
     Nonconserving = 0,
@@ -43,15 +43,17 @@ pub struct PoseIntegratorCallbacks {
     /// # Arguments
     ///
     /// * `simulation`: Simulation to which these callbacks belong.
-    pub initialize: Option<unsafe extern "C" fn(simulation: SimulationHandle)>,
+    /// * `data`: Opaque pointer the simulation was created with.
+    pub initialize: Option<unsafe extern "C" fn(simulation: SimulationHandle, data: *mut ())>,
     /// Called before each simulation stage which could execute velocity integration.
     ///
     /// # Arguments
     ///
     /// * `simulation`: Simulation to which these callbacks belong.
     /// * `dt`: Timestep duration that subsequent velocity integrations will be invoked with.
+    /// * `data`: Opaque pointer the simulation was created with.
     pub prepare_for_integration:
-        Option<unsafe extern "C" fn(simulation: SimulationHandle, dt: f32)>,
+        Option<unsafe extern "C" fn(simulation: SimulationHandle, dt: f32, data: *mut ())>,
     /// Called for every active body during each integration pass when `use_scalar_callback` is true.
     ///
     /// # Arguments
@@ -64,6 +66,7 @@ pub struct PoseIntegratorCallbacks {
     /// * `worker_index`: Index of the thread worker processing this callback.
     /// * `dt`: Timestep duration that subsequent velocity integrations will be invoked with.
     /// * `velocity`: Velocity of the body to be updated by this callback.
+    /// * `data`: Opaque pointer the simulation was created with.
     pub integrate_velocity: unsafe extern "C" fn(
         simulation: SimulationHandle,
         body_index: *const Simd<i32, WIDEST_LANE>,
@@ -72,7 +75,7 @@ pub struct PoseIntegratorCallbacks {
         local_inertia: *mut BodyInertiaWide,
         mask: *const Mask<i32, WIDEST_LANE>,
         worker_index: i32,
-        dt: Simd<f32, WIDEST_LANE>,
+        dt: *const Simd<f32, WIDEST_LANE>,
         velocity: *mut BodyVelocityWide,
         data: *mut (),
     ),
@@ -6,7 +6,7 @@ use super::{
 
 /// Represents how a collidable can interact and move.
 #[repr(C)]
-#[derive(PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CollidableMobility {
     /// Marks a collidable as owned by a dynamic body.
     Dynamic = 0,
@@ -18,6 +18,7 @@ pub enum CollidableMobility {
 
 /// Uses a bitpacked representation to refer to a body or static collidable.
 #[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct CollidableReference {
     /// Bitpacked representation of the collidable reference.
     pub packed: u32,
@@ -66,6 +67,7 @@ impl CollidableReference {
 }
 
 #[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct CollidablePair {
     pub a: CollidableReference,
     pub b: CollidableReference,
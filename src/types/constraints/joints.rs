@@ -0,0 +1,97 @@
+use crate::types::math::scalar::{Quaternion, Vector3};
+
+use super::{servos::ServoSettings, springs::SpringSettings};
+
+/// Constrains a point on each body to be coincident.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct BallSocket {
+    /// Offset from the center of body A to its attachment in A's local space.
+    pub local_offset_a: Vector3,
+    /// Offset from the center of body B to its attachment in B's local space.
+    pub local_offset_b: Vector3,
+    /// Spring frequency and damping parameters.
+    pub spring_settings: SpringSettings,
+}
+
+/// Constrains two bodies with a hinge. Equivalent to a [`BallSocket`] combined with an [`AngularHinge`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct Hinge {
+    /// Local offset from the center of body A to its attachment point.
+    pub local_offset_a: Vector3,
+    /// Hinge axis in the local space of A.
+    pub local_hinge_axis_a: Vector3,
+    /// Local offset from the center of body B to its attachment point.
+    pub local_offset_b: Vector3,
+    /// Hinge axis in the local space of B.
+    pub local_hinge_axis_b: Vector3,
+    /// Spring frequency and damping parameters.
+    pub spring_settings: SpringSettings,
+}
+
+/// Constrains two bodies to maintain a relative position and orientation.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct Weld {
+    /// Target offset from A to B in the local space of A.
+    pub local_offset: Vector3,
+    /// Target orientation of B in the local space of A.
+    pub local_orientation: Quaternion,
+    /// Spring frequency and damping parameters.
+    pub spring_settings: SpringSettings,
+}
+
+/// Constrains two bodies with a swivel hinge that allows rotation around two axes, like a laptop monitor hinge that allows flipping the screen.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SwivelHinge {
+    /// Local offset from the center of body A to its attachment point.
+    pub local_offset_a: Vector3,
+    /// Swivel axis in the local space of body A.
+    pub local_swivel_axis_a: Vector3,
+    /// Local offset from the center of body B to its attachment point.
+    pub local_offset_b: Vector3,
+    /// Hinge axis in the local space of body B.
+    pub local_hinge_axis_b: Vector3,
+    /// Spring frequency and damping parameters.
+    pub spring_settings: SpringSettings,
+}
+
+/// Angular component of a hinge. Constrains the angular degrees of freedom of two bodies such that they can only rotate relative to each other around the hinge's axis.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct AngularHinge {
+    /// Hinge axis in the local space of A.
+    pub local_hinge_axis_a: Vector3,
+    /// Hinge axis in the local space of B.
+    pub local_hinge_axis_b: Vector3,
+    /// Spring frequency and damping parameters.
+    pub spring_settings: SpringSettings,
+}
+
+/// Constrains a point on body B to be on a line attached to body A.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct PointOnLineServo {
+    /// Local offset from the center of body A to the origin of the line.
+    pub local_offset_a: Vector3,
+    /// Local offset from the center of body B to the point constrained to the line.
+    pub local_offset_b: Vector3,
+    /// Direction of the line in the local space of body A.
+    pub local_direction: Vector3,
+    /// Servo control parameters.
+    pub servo_settings: ServoSettings,
+    /// Spring frequency and damping parameters.
+    pub spring_settings: SpringSettings,
+}
+
+/// Constrains the centers of two bodies to be separated by a goal distance.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct CenterDistanceConstraint {
+    /// Target distance between the body centers.
+    pub target_distance: f32,
+    /// Spring frequency and damping parameters.
+    pub spring_settings: SpringSettings,
+}
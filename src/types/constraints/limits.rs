@@ -0,0 +1,79 @@
+use crate::types::math::scalar::{Quaternion, Vector3};
+
+use super::springs::SpringSettings;
+
+/// Constrains the relative twist angle between two bodies to lie within a range.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct TwistLimit {
+    /// Local space basis attached to body A against which to measure body B's transformed axis. Expressed as a 3x3 rotation matrix, the X axis corresponds with 0 degrees, the Y axis corresponds to 90 degrees, and the Z axis is the twist axis.
+    pub local_basis_a: Quaternion,
+    /// Local space basis attached to body B that will be measured against body A's basis.
+    pub local_basis_b: Quaternion,
+    /// Minimum angle between B's axis to measure and A's measurement axis.
+    pub minimum_angle: f32,
+    /// Maximum angle between B's axis to measure and A's measurement axis.
+    pub maximum_angle: f32,
+    /// Spring frequency and damping parameters.
+    pub spring_settings: SpringSettings,
+}
+
+/// Constrains the angle between two axes attached to two bodies to be below a maximum.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SwingLimit {
+    /// Axis attached to body A in its local space.
+    pub axis_local_a: Vector3,
+    /// Axis attached to body B in its local space.
+    pub axis_local_b: Vector3,
+    /// Minimum dot product between the world space A and B axes that the constraint attempts to maintain.
+    pub minimum_dot: f32,
+    /// Spring frequency and damping parameters.
+    pub spring_settings: SpringSettings,
+}
+
+impl SwingLimit {
+    /// Gets the maximum swing angle that the constraint allows between world axis A and B. Equivalent to `acos(minimum_dot)`.
+    pub fn maximum_swing_angle(&self) -> f32 {
+        self.minimum_dot.acos()
+    }
+
+    /// Sets the maximum swing angle that the constraint allows between world axis A and B.
+    pub fn set_maximum_swing_angle(&mut self, value: f32) {
+        self.minimum_dot = value.cos();
+    }
+}
+
+/// Constrains the distance between points on two bodies to lie within a range.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DistanceLimit {
+    /// Local offset from the center of body A to its attachment point.
+    pub local_offset_a: Vector3,
+    /// Local offset from the center of body B to its attachment point.
+    pub local_offset_b: Vector3,
+    /// Minimum distance permitted between the point on A and the point on B.
+    pub minimum_distance: f32,
+    /// Maximum distance permitted between the point on A and the point on B.
+    pub maximum_distance: f32,
+    /// Spring frequency and damping parameters.
+    pub spring_settings: SpringSettings,
+}
+
+/// Constrains the offset of two points along an axis to lie within a range.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct LinearAxisLimit {
+    /// Local offset from the center of body A to its attachment point.
+    pub local_offset_a: Vector3,
+    /// Local offset from the center of body B to its attachment point.
+    pub local_offset_b: Vector3,
+    /// Direction of the limited axis in the local space of body A.
+    pub local_axis: Vector3,
+    /// Minimum offset along the world axis between A and B's anchor points.
+    pub minimum_offset: f32,
+    /// Maximum offset along the world axis between A and B's anchor points.
+    pub maximum_offset: f32,
+    /// Spring frequency and damping parameters.
+    pub spring_settings: SpringSettings,
+}
@@ -1 +1,5 @@
+pub mod joints;
+pub mod limits;
+pub mod motors;
+pub mod servos;
 pub mod springs;
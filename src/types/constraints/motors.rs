@@ -0,0 +1,77 @@
+use crate::types::math::scalar::Vector3;
+
+/// Describes how strongly and how softly a motor constraint drives towards its target velocity.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct MotorSettings {
+    /// Maximum force that the motor can apply.
+    pub maximum_force: f32,
+    damping: f32,
+}
+
+impl MotorSettings {
+    /// Gets the amount of motor softness. Higher values make the motor approach its target velocity more slowly; 0 is perfectly rigid.
+    pub fn softness(&self) -> f32 {
+        1.0 / self.damping
+    }
+
+    /// Sets the amount of motor softness. Higher values make the motor approach its target velocity more slowly; 0 is perfectly rigid.
+    pub fn set_softness(&mut self, value: f32) {
+        self.damping = if value <= 0.0 { f32::MAX } else { 1.0 / value };
+    }
+
+    /// Constructs a new motor settings instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `maximum_force`: Maximum force that the motor can apply.
+    /// * `softness`: Amount of motor softness. 0 is perfectly rigid.
+    pub fn new(maximum_force: f32, softness: f32) -> Self {
+        let mut settings = Self {
+            maximum_force,
+            damping: 0.0,
+        };
+        settings.set_softness(softness);
+        settings
+    }
+}
+
+/// Constrains the relative angular velocity of two bodies to a target.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct AngularMotor {
+    /// Target relative angular velocity between A and B, stored in A's local space.
+    pub target_velocity_local_a: Vector3,
+    /// Motor control parameters.
+    pub settings: MotorSettings,
+}
+
+/// Constrains the relative linear velocity of two points along an axis to a target.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct LinearAxisMotor {
+    /// Local offset from the center of body A to its attachment point.
+    pub local_offset_a: Vector3,
+    /// Local offset from the center of body B to its attachment point.
+    pub local_offset_b: Vector3,
+    /// Direction of the motorized axis in the local space of body A.
+    pub local_axis: Vector3,
+    /// Target relative velocity along the axis.
+    pub target_velocity: f32,
+    /// Motor control parameters.
+    pub settings: MotorSettings,
+}
+
+/// Constrains the relative twist velocity between two bodies to a target.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct TwistMotor {
+    /// Local space axis attached to body A against which to measure twist.
+    pub local_axis_a: Vector3,
+    /// Local space axis attached to body B against which to measure twist.
+    pub local_axis_b: Vector3,
+    /// Target relative twist velocity around the axes.
+    pub target_velocity: f32,
+    /// Motor control parameters.
+    pub settings: MotorSettings,
+}
@@ -0,0 +1,116 @@
+use crate::types::math::scalar::{Quaternion, Vector3};
+
+use super::springs::SpringSettings;
+
+/// Describes how quickly and strongly a servo constraint can move towards its target.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ServoSettings {
+    /// Maximum speed that the servo will try to use to reach its target.
+    pub maximum_speed: f32,
+    /// Minimum speed that the servo will use to approach its target.
+    /// The servo will use the spring settings to approach the target unless the speed is below this value.
+    pub base_speed: f32,
+    /// Maximum force that the servo can apply to reach its target.
+    pub maximum_force: f32,
+}
+
+impl ServoSettings {
+    /// Constructs a new servo settings instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `maximum_speed`: Maximum speed that the servo will try to use to reach its target.
+    /// * `base_speed`: Minimum speed that the servo will use to approach its target.
+    /// * `maximum_force`: Maximum force that the servo can apply to reach its target.
+    pub fn new(maximum_speed: f32, base_speed: f32, maximum_force: f32) -> Self {
+        Self {
+            maximum_speed,
+            base_speed,
+            maximum_force,
+        }
+    }
+}
+
+impl Default for ServoSettings {
+    fn default() -> Self {
+        // Matches `ServoSettings.Default` in BepuPhysics: unlimited speed and force, so the servo behaves like a plain spring.
+        Self {
+            maximum_speed: f32::MAX,
+            base_speed: 0.0,
+            maximum_force: f32::MAX,
+        }
+    }
+}
+
+/// Constrains two bodies to reach a target relative orientation.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct AngularServo {
+    /// Target relative rotation from A to B, in A's local space.
+    pub target_relative_rotation_local_a: Quaternion,
+    /// Spring frequency and damping parameters.
+    pub spring_settings: SpringSettings,
+    /// Servo control parameters.
+    pub servo_settings: ServoSettings,
+}
+
+/// Constrains the relative offset of two points along an axis to a target.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct LinearAxisServo {
+    /// Local offset from the center of body A to its attachment point.
+    pub local_offset_a: Vector3,
+    /// Local offset from the center of body B to its attachment point.
+    pub local_offset_b: Vector3,
+    /// Normal of the plane the offset is measured against, in the local space of body A.
+    pub local_plane_normal: Vector3,
+    /// Target offset from A's plane anchor to B's anchor along the plane normal.
+    pub target_offset: f32,
+    /// Servo control parameters.
+    pub servo_settings: ServoSettings,
+    /// Spring frequency and damping parameters.
+    pub spring_settings: SpringSettings,
+}
+
+/// Pulls a point on a single body towards a target location in world space.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct OneBodyLinearServo {
+    /// Offset to the attachment point in the local space of the body.
+    pub local_offset: Vector3,
+    /// Target position in world space.
+    pub target: Vector3,
+    /// Servo control parameters.
+    pub servo_settings: ServoSettings,
+    /// Spring frequency and damping parameters.
+    pub spring_settings: SpringSettings,
+}
+
+/// Rotates a single body towards a target orientation in world space.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct OneBodyAngularServo {
+    /// Target orientation of the body.
+    pub target_orientation: Quaternion,
+    /// Servo control parameters.
+    pub servo_settings: ServoSettings,
+    /// Spring frequency and damping parameters.
+    pub spring_settings: SpringSettings,
+}
+
+/// Constrains the relative twist angle between two bodies to a target.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct TwistServo {
+    /// Local space basis attached to body A against which to measure body B's transformed axis. Expressed as a 3x3 rotation matrix, the X axis corresponds with 0 degrees, the Y axis corresponds to 90 degrees, and the Z axis is the twist axis.
+    pub local_basis_a: Quaternion,
+    /// Local space basis attached to body B that will be measured against body A's basis.
+    pub local_basis_b: Quaternion,
+    /// Target angle between B's axis to measure and A's measurement axis.
+    pub target_angle: f32,
+    /// Spring frequency and damping parameters.
+    pub spring_settings: SpringSettings,
+    /// Servo control parameters.
+    pub servo_settings: ServoSettings,
+}
@@ -7,6 +7,7 @@ pub const TWO_PI: f32 = std::f32::consts::PI * 2.0;
 
 /// Settings for a spring.
 #[repr(C)]
+#[derive(Clone, Copy, Debug)]
 pub struct SpringSettings {
     /// Target number of undamped oscillations per unit of time, scaled by 2 * PI.
     pub angular_frequency: f32,
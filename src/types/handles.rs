@@ -25,12 +25,16 @@ impl TypedIndex {
 
 /// Points to an instance in an instance directory.
 #[repr(C)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct InstanceHandle {
     pub raw_value: i32,
 }
 
 impl InstanceHandle {
+    /// Creates a handle that refers to no instance.
+    pub fn null() -> Self {
+        Self { raw_value: 0 }
+    }
     pub fn index(&self) -> i32 {
         self.raw_value & 0x00FFFFFF
     }
@@ -47,17 +51,17 @@ impl InstanceHandle {
 }
 
 #[repr(C)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct BodyHandle {
     pub value: i32,
 }
 #[repr(C)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct StaticHandle {
     pub value: i32,
 }
 #[repr(C)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct ConstraintHandle {
     pub value: i32,
 }
@@ -1,4 +1,5 @@
 #[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Vector3 {
     pub x: f32,
     pub y: f32,
@@ -22,6 +23,7 @@ impl Default for Vector3 {
 
 /// A quaternion.
 #[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Quaternion {
     pub x: f32,
     pub y: f32,
@@ -18,8 +18,10 @@ pub struct QuaternionWide {
 
 #[repr(C)]
 pub struct RigidPoseWide {
-    position: Vector3Wide,
-    orientation: QuaternionWide,
+    /// Positions of the poses in the bundle.
+    pub position: Vector3Wide,
+    /// Orientations of the poses in the bundle.
+    pub orientation: QuaternionWide,
 }
 
 #[repr(C)]
@@ -40,12 +42,16 @@ pub struct Symmetric3x3Wide {
 
 #[repr(C)]
 pub struct BodyInertiaWide {
-    inverse_tensor: Symmetric3x3Wide,
-    inverse_mass: Simd<f32, WIDEST_LANE>,
+    /// Inverse inertia tensors of the bodies in the bundle.
+    pub inverse_inertia_tensor: Symmetric3x3Wide,
+    /// Inverse masses of the bodies in the bundle.
+    pub inverse_mass: Simd<f32, WIDEST_LANE>,
 }
 
 #[repr(C)]
 pub struct BodyVelocityWide {
-    linear: Vector3Wide,
-    angular: Vector3Wide,
+    /// Linear velocities of the bodies in the bundle.
+    pub linear: Vector3Wide,
+    /// Angular velocities of the bodies in the bundle.
+    pub angular: Vector3Wide,
 }
@@ -5,16 +5,20 @@ pub mod collisions;
 pub mod constraints;
 pub mod handles;
 pub mod math;
+pub mod queries;
 pub mod shapes;
 pub mod simulation;
 pub mod statics;
 pub mod utilities;
 
-#[cfg(target_feature = "avx512f")]
-pub const WIDEST_LANE: usize = 16;
-
-#[cfg(all(target_feature = "sse2", not(target_feature = "avx512f")))]
+/// Number of lanes in the wide types Bepu passes to callbacks, matching `Vector<float>` in the native library.
+///
+/// Depends on the instruction set the native library was compiled for; set by the build script.
+#[cfg(bepuvy_simd_width = "8")]
 pub const WIDEST_LANE: usize = 8;
 
-#[cfg(not(any(target_feature = "avx512f", target_feature = "sse2")))]
-pub const WIDEST_LANE: usize = 4; // Fallback for systems without AVX512F or SSE2
+/// Number of lanes in the wide types Bepu passes to callbacks, matching `Vector<float>` in the native library.
+///
+/// Depends on the instruction set the native library was compiled for; set by the build script.
+#[cfg(not(bepuvy_simd_width = "8"))]
+pub const WIDEST_LANE: usize = 4;
@@ -0,0 +1,94 @@
+use super::{collisions::CollidableReference, math::scalar::Vector3};
+
+/// Information about a ray's intersection with a collidable.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct RayHit {
+    /// Distance along the ray to the impact, measured in units of the ray direction's length.
+    pub t: f32,
+    /// Surface normal at the impact point.
+    pub normal: Vector3,
+    /// Collidable hit by the ray.
+    pub collidable: CollidableReference,
+    /// Index of the child hit within the collidable's shape, for compounds and meshes. 0 for convex shapes.
+    pub child_index: i32,
+}
+
+/// Callbacks invoked by the simulation while traversing a ray through the broad phase.
+#[repr(C)]
+pub struct RayHitHandler {
+    /// Opaque pointer passed to every callback.
+    pub context: *mut (),
+    /// Called before a collidable is tested against the ray. Returning false skips the collidable.
+    /// If `None`, every collidable is tested.
+    ///
+    /// # Arguments
+    ///
+    /// * `context`: Context pointer stored in the handler.
+    /// * `collidable`: Collidable about to be tested.
+    pub allow_test:
+        Option<unsafe extern "C" fn(context: *mut (), collidable: CollidableReference) -> bool>,
+    /// Called for every intersection found.
+    ///
+    /// # Arguments
+    ///
+    /// * `context`: Context pointer stored in the handler.
+    /// * `hit`: Intersection that was found.
+    /// * `maximum_t`: Maximum distance along the ray that will still be tested. Lowering it stops traversal of anything further away.
+    pub on_ray_hit: unsafe extern "C" fn(context: *mut (), hit: *const RayHit, maximum_t: *mut f32),
+}
+
+/// Information about a swept shape's impact with a collidable.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SweepHit {
+    /// Time of impact along the sweep, in units of the sweep's velocity.
+    pub t: f32,
+    /// Location of the first contact at the time of impact.
+    pub location: Vector3,
+    /// Surface normal at the contact, pointing from the hit collidable towards the swept shape.
+    pub normal: Vector3,
+    /// Collidable hit by the sweep.
+    pub collidable: CollidableReference,
+    /// Whether the swept shape was already overlapping the collidable at the start of the sweep.
+    /// If true, `t` is 0 and `location` and `normal` carry no information.
+    pub starts_overlapping: bool,
+}
+
+/// Callbacks invoked by the simulation while sweeping a shape through the broad phase.
+#[repr(C)]
+pub struct SweepHitHandler {
+    /// Opaque pointer passed to every callback.
+    pub context: *mut (),
+    /// Called before a collidable is tested against the sweep. Returning false skips the collidable.
+    /// If `None`, every collidable is tested.
+    ///
+    /// # Arguments
+    ///
+    /// * `context`: Context pointer stored in the handler.
+    /// * `collidable`: Collidable about to be tested.
+    pub allow_test:
+        Option<unsafe extern "C" fn(context: *mut (), collidable: CollidableReference) -> bool>,
+    /// Called for every impact found.
+    ///
+    /// # Arguments
+    ///
+    /// * `context`: Context pointer stored in the handler.
+    /// * `hit`: Impact that was found.
+    /// * `maximum_t`: Maximum time of impact that will still be tested. Lowering it stops traversal of anything further away.
+    pub on_hit: unsafe extern "C" fn(context: *mut (), hit: *const SweepHit, maximum_t: *mut f32),
+}
+
+/// Callbacks invoked by the simulation for every collidable whose broad-phase bounds overlap a query box.
+#[repr(C)]
+pub struct BroadPhaseOverlapHandler {
+    /// Opaque pointer passed to every callback.
+    pub context: *mut (),
+    /// Called for each overlapping collidable. Returning false stops the query.
+    ///
+    /// # Arguments
+    ///
+    /// * `context`: Context pointer stored in the handler.
+    /// * `collidable`: Collidable whose bounds overlap the query box.
+    pub on_overlap: unsafe extern "C" fn(context: *mut (), collidable: CollidableReference) -> bool,
+}
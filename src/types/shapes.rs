@@ -10,7 +10,7 @@ use super::WIDEST_LANE;
 
 /// Shape type enum.
 #[repr(C)]
-#[derive(PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ShapeTypes {
     Sphere = 0,
     Capsule = 1,
@@ -62,6 +62,7 @@ impl Box {
 
 /// A triangle shape.
 #[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
 pub struct Triangle {
     /// First vertex of the triangle in local space.
     pub a: Vector3,
@@ -120,6 +121,7 @@ pub struct ConvexHull {
 
 /// Shape and pose of a child within a compound shape.
 #[repr(C)]
+#[derive(Clone, Copy)]
 pub struct CompoundChild {
     /// Local orientation of the child in the compound.
     pub local_orientation: Quaternion,
@@ -17,10 +17,14 @@ pub struct SolveDescription {
     ///
     /// # Arguments
     ///
+    /// * `context`: The `velocity_iteration_scheduler_context` of this description.
     /// * `substep_index`: Index of the substep to schedule velocity iterations for.
     ///
     /// returns: Number of velocity iterations to run during this substep.
-    pub velocity_iteration_scheduler: Option<fn(i32) -> i32>,
+    pub velocity_iteration_scheduler:
+        Option<unsafe extern "C" fn(context: *mut (), substep_index: i32) -> i32>,
+    /// Context passed to every call of `velocity_iteration_scheduler`. Must stay valid for as long as the simulation is alive.
+    pub velocity_iteration_scheduler_context: *mut (),
 }
 
 impl Default for SolveDescription {
@@ -32,6 +36,7 @@ impl Default for SolveDescription {
             substep_count: 1,
             fallback_batch_threshold: 128,
             velocity_iteration_scheduler: None,
+            velocity_iteration_scheduler_context: std::ptr::null_mut(),
         }
     }
 }
@@ -54,6 +59,7 @@ impl SolveDescription {
             substep_count,
             fallback_batch_threshold,
             velocity_iteration_scheduler: None,
+            velocity_iteration_scheduler_context: std::ptr::null_mut(),
         }
     }
 }
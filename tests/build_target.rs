@@ -0,0 +1,121 @@
+// The build script's target detection, included directly since build scripts can't be imported.
+#[allow(dead_code)]
+#[path = "../build/target.rs"]
+mod target;
+
+use target::{simd_width, Target};
+
+const V2: &str = "cmpxchg16b,fxsr,popcnt,sse,sse2,sse3,sse4.1,sse4.2,ssse3";
+const V3: &str = "avx,avx2,bmi1,bmi2,cmpxchg16b,f16c,fma,fxsr,lzcnt,movbe,popcnt,sse,sse2,sse3,sse4.1,sse4.2,ssse3,xsave";
+
+fn linux_x64(features: &str) -> Target {
+    Target::new("linux", "x86_64", "gnu", features)
+}
+
+#[test]
+fn supported_targets_map_to_runtime_identifiers() {
+    assert_eq!(Target::new("macos", "aarch64", "", "").rid, "osx-arm64");
+    assert_eq!(Target::new("windows", "x86_64", "msvc", "").rid, "win-x64");
+    assert_eq!(Target::new("windows", "x86_64", "gnu", "").rid, "win-x64");
+    assert_eq!(Target::new("linux", "x86_64", "gnu", "").rid, "linux-x64");
+    assert_eq!(
+        Target::new("linux", "x86_64", "musl", "").rid,
+        "linux-musl-x64"
+    );
+    assert_eq!(
+        Target::new("linux", "aarch64", "gnu", "").rid,
+        "linux-arm64"
+    );
+}
+
+#[test]
+#[should_panic(expected = "aarch64-linux-musl is not supported")]
+fn unsupported_target_panics() {
+    Target::new("linux", "aarch64", "musl", "");
+}
+
+#[test]
+fn features_are_split_on_commas() {
+    assert!(linux_x64("").features.is_empty());
+    assert_eq!(linux_x64("sse2,avx2").features, ["sse2", "avx2"]);
+    assert!(Target::new("windows", "x86_64", "msvc", "").is_windows());
+    assert!(!linux_x64("").is_windows());
+}
+
+#[test]
+fn x64_baseline_leaves_instruction_set_to_compiler() {
+    assert_eq!(
+        linux_x64("fxsr,sse,sse2").select_instruction_set(None),
+        None
+    );
+}
+
+#[test]
+fn x64_picks_most_capable_supported_level() {
+    assert_eq!(
+        linux_x64(V2).select_instruction_set(None).as_deref(),
+        Some("x86-x64-v2")
+    );
+    assert_eq!(
+        linux_x64(V3).select_instruction_set(None).as_deref(),
+        Some("x86-x64-v3")
+    );
+    let v4 = format!("{V3},avx512f,avx512bw,avx512cd,avx512dq,avx512vl");
+    assert_eq!(
+        linux_x64(&v4).select_instruction_set(None).as_deref(),
+        Some("x86-x64-v4")
+    );
+    assert_eq!(
+        Target::new("windows", "x86_64", "msvc", V3)
+            .select_instruction_set(None)
+            .as_deref(),
+        Some("x86-x64-v3")
+    );
+}
+
+#[test]
+fn x64_level_needs_every_feature() {
+    // v3 without fma falls back to v2.
+    let partial_v3 = V3.replace(",fma", "");
+    assert_eq!(
+        linux_x64(&partial_v3)
+            .select_instruction_set(None)
+            .as_deref(),
+        Some("x86-x64-v2")
+    );
+}
+
+#[test]
+fn arm_targets() {
+    assert_eq!(
+        Target::new("macos", "aarch64", "", "neon")
+            .select_instruction_set(None)
+            .as_deref(),
+        Some("apple-m1")
+    );
+    assert_eq!(
+        Target::new("linux", "aarch64", "gnu", "neon").select_instruction_set(None),
+        None
+    );
+}
+
+#[test]
+fn override_replaces_detected_instruction_set() {
+    assert_eq!(
+        linux_x64("")
+            .select_instruction_set(Some("native"))
+            .as_deref(),
+        Some("native")
+    );
+    assert_eq!(linux_x64(V3).select_instruction_set(Some("")), None);
+}
+
+#[test]
+fn simd_width_follows_avx2() {
+    assert_eq!(simd_width(None), 4);
+    assert_eq!(simd_width(Some("x86-x64-v2")), 4);
+    assert_eq!(simd_width(Some("apple-m1")), 4);
+    assert_eq!(simd_width(Some("x86-x64-v3")), 8);
+    assert_eq!(simd_width(Some("x86-x64-v4")), 8);
+    assert_eq!(simd_width(Some("avx2,bmi1,bmi2")), 8);
+}
@@ -0,0 +1,66 @@
+use bepuvy_sys::bepu::buffer_pool::BufferPool;
+
+#[test]
+fn alloc_default_initializes() {
+    let pool = BufferPool::default();
+    let buffer = pool.alloc::<u32>(16);
+    assert_eq!(buffer.len(), 16);
+    assert!(buffer.iter().all(|&value| value == 0));
+    assert!(std::ptr::eq(buffer.pool(), &pool));
+}
+
+#[test]
+fn alloc_from_slice_copies_values() {
+    let pool = BufferPool::default();
+    let values: Vec<u64> = (0..100).collect();
+    let buffer = pool.alloc_from_slice(&values);
+    assert_eq!(&*buffer, values.as_slice());
+}
+
+#[test]
+fn alloc_empty() {
+    let pool = BufferPool::default();
+    let buffer = pool.alloc::<f32>(0);
+    assert!(buffer.is_empty());
+    let buffer = pool.alloc_from_slice::<f32>(&[]);
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn resize_grow_keeps_leading_elements() {
+    let pool = BufferPool::default();
+    let mut buffer = pool.alloc_from_slice(&[1u32, 2, 3]);
+    buffer.resize(1000);
+    assert_eq!(buffer.len(), 1000);
+    assert_eq!(&buffer[..3], &[1, 2, 3]);
+    assert!(buffer[3..].iter().all(|&value| value == 0));
+}
+
+#[test]
+fn resize_shrink_keeps_leading_elements() {
+    let pool = BufferPool::default();
+    let values: Vec<i32> = (0..64).collect();
+    let mut buffer = pool.alloc_from_slice(&values);
+    buffer.resize(5);
+    assert_eq!(&*buffer, &[0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn resize_through_zero() {
+    let pool = BufferPool::default();
+    let mut buffer = pool.alloc_from_slice(&[7u8; 32]);
+    buffer.resize(0);
+    assert!(buffer.is_empty());
+    buffer.resize(8);
+    assert_eq!(&*buffer, &[0; 8]);
+}
+
+#[test]
+fn resize_after_writes_default_fills_only_new_elements() {
+    let pool = BufferPool::default();
+    let mut buffer = pool.alloc::<u16>(4);
+    buffer.copy_from_slice(&[9, 8, 7, 6]);
+    buffer.resize(2);
+    buffer.resize(6);
+    assert_eq!(&*buffer, &[9, 8, 0, 0, 0, 0]);
+}
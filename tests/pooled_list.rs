@@ -0,0 +1,142 @@
+use bepuvy_sys::bepu::buffer_pool::BufferPool;
+use bepuvy_sys::bepu::collections::PooledList;
+
+fn list_of(pool: &BufferPool, len: u32) -> PooledList<'_, u32> {
+    let mut list = PooledList::new(pool);
+    list.extend(0..len);
+    list
+}
+
+#[test]
+fn new_list_doesnt_allocate() {
+    let pool = BufferPool::default();
+    let list = PooledList::<u32>::new(&pool);
+    assert!(list.is_empty());
+    assert_eq!(list.capacity(), 0);
+}
+
+#[test]
+fn push_and_pop() {
+    let pool = BufferPool::default();
+    let mut list = PooledList::new(&pool);
+    for i in 0..100u32 {
+        list.push(i);
+    }
+    assert_eq!(list.len(), 100);
+    for i in (0..100u32).rev() {
+        assert_eq!(list.pop(), Some(i));
+    }
+    assert_eq!(list.pop(), None);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn extend_from_slice_grows_past_capacity() {
+    let pool = BufferPool::default();
+    let mut list = PooledList::with_capacity(&pool, 2);
+    assert!(list.capacity() >= 2);
+    list.push(7u64);
+    let values: Vec<u64> = (0..500).collect();
+    list.extend_from_slice(&values);
+    assert_eq!(list.len(), 501);
+    assert_eq!(list[0], 7);
+    assert_eq!(&list[1..], values.as_slice());
+    list.extend_from_slice(&[]);
+    assert_eq!(list.len(), 501);
+}
+
+#[test]
+fn reserve_keeps_elements() {
+    let pool = BufferPool::default();
+    let mut list = list_of(&pool, 10);
+    list.reserve(1000);
+    assert!(list.capacity() >= 1010);
+    assert_eq!(&*list, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+}
+
+#[test]
+fn drain_middle_shifts_tail() {
+    let pool = BufferPool::default();
+    let mut list = list_of(&pool, 10);
+    let drained: Vec<u32> = list.drain(2..5).collect();
+    assert_eq!(drained, [2, 3, 4]);
+    assert_eq!(&*list, &[0, 1, 5, 6, 7, 8, 9]);
+}
+
+#[test]
+fn drain_partially_consumed_still_shifts_tail() {
+    let pool = BufferPool::default();
+    let mut list = list_of(&pool, 10);
+    {
+        let mut drain = list.drain(1..=6);
+        assert_eq!(drain.len(), 6);
+        assert_eq!(drain.next(), Some(1));
+        assert_eq!(drain.next_back(), Some(6));
+        assert_eq!(drain.len(), 4);
+    }
+    assert_eq!(&*list, &[0, 7, 8, 9]);
+}
+
+#[test]
+fn drain_back_to_front() {
+    let pool = BufferPool::default();
+    let mut list = list_of(&pool, 6);
+    let drained: Vec<u32> = list.drain(2..).rev().collect();
+    assert_eq!(drained, [5, 4, 3, 2]);
+    assert_eq!(&*list, &[0, 1]);
+}
+
+#[test]
+fn drain_full_and_empty_ranges() {
+    let pool = BufferPool::default();
+    let mut list = list_of(&pool, 5);
+    assert_eq!(list.drain(3..3).count(), 0);
+    assert_eq!(&*list, &[0, 1, 2, 3, 4]);
+    let capacity = list.capacity();
+    assert_eq!(list.drain(..).count(), 5);
+    assert!(list.is_empty());
+    assert_eq!(list.capacity(), capacity);
+}
+
+#[test]
+fn leaked_drain_truncates_list() {
+    let pool = BufferPool::default();
+    let mut list = list_of(&pool, 10);
+    std::mem::forget(list.drain(4..6));
+    assert_eq!(&*list, &[0, 1, 2, 3]);
+    list.push(42);
+    assert_eq!(&*list, &[0, 1, 2, 3, 42]);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn drain_past_end_panics() {
+    let pool = BufferPool::default();
+    let mut list = list_of(&pool, 3);
+    list.drain(1..4);
+}
+
+#[test]
+fn clear_keeps_capacity() {
+    let pool = BufferPool::default();
+    let mut list = list_of(&pool, 20);
+    let capacity = list.capacity();
+    list.clear();
+    assert!(list.is_empty());
+    assert_eq!(list.capacity(), capacity);
+    list.extend(&[3, 2, 1]);
+    assert_eq!(&*list, &[3, 2, 1]);
+}
+
+#[test]
+fn quick_list_round_trip() {
+    let pool = BufferPool::default();
+    let list = list_of(&pool, 8);
+    let quick_list = list.into_quick_list();
+    assert_eq!(quick_list.count, 8);
+    // SAFETY:
+    //
+    // The quick list was released by a list allocated from the same pool.
+    let list = unsafe { PooledList::from_quick_list(&pool, quick_list) };
+    assert_eq!(&*list, &[0, 1, 2, 3, 4, 5, 6, 7]);
+}